@@ -0,0 +1,133 @@
+use proc_macro::*;
+use quote::quote;
+
+pub fn define_math_family_impl(input: TokenStream) -> TokenStream {
+    if input.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "expected scalar type, e.g. define_math_family!(f32)"
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let scalar = match syn::parse::<syn::Type>(input) {
+        Ok(scalar) => scalar,
+        Err(err) => {
+            return syn::Error::new(err.span(), "expected scalar type, e.g. define_math_family!(f32)")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let expanded = quote! {
+        #[derive(Clone, Copy, ::macro_utils::VectorMath)]
+        pub struct Vec2 {
+            pub x: #scalar,
+            pub y: #scalar,
+        }
+
+        #[derive(Clone, Copy, ::macro_utils::VectorMath)]
+        pub struct Vec3 {
+            pub x: #scalar,
+            pub y: #scalar,
+            pub z: #scalar,
+        }
+
+        #[derive(Clone, Copy, ::macro_utils::VectorMath)]
+        pub struct Vec4 {
+            pub x: #scalar,
+            pub y: #scalar,
+            pub z: #scalar,
+            pub w: #scalar,
+        }
+
+        #[::macro_utils::mat_vec_mul(Vec2, row(Vec2), ref)]
+        pub type Mat2 = ::gk_math::base::matrix::Mat<#scalar, 2, 2>;
+
+        #[::macro_utils::mat_vec_mul(Vec3, row(Vec3), ref)]
+        pub type Mat3 = ::gk_math::base::matrix::Mat<#scalar, 3, 3>;
+
+        #[::macro_utils::mat_vec_mul(Vec4, row(Vec4), ref)]
+        pub type Mat4 = ::gk_math::base::matrix::Mat<#scalar, 4, 4>;
+
+        #[::macro_utils::mat_vec_mul(Vec4 -> Vec3, row(Vec3 -> Vec4), ref)]
+        pub type Mat3x4 = ::gk_math::base::matrix::Mat<#scalar, 3, 4>;
+
+        impl Vec2 {
+            #[inline]
+            pub fn extend(self, z: #scalar) -> Vec3 {
+                Vec3::new(self.x, self.y, z)
+            }
+        }
+
+        impl Vec3 {
+            #[inline]
+            pub fn extend(self, w: #scalar) -> Vec4 {
+                Vec4::new(self.x, self.y, self.z, w)
+            }
+
+            #[inline]
+            pub fn truncate(self) -> Vec2 {
+                Vec2::new(self.x, self.y)
+            }
+
+            /// Homogeneous 2D point to cartesian, divide by `z`.
+            #[inline]
+            pub fn project(self) -> Vec2 {
+                Vec2::new(self.x / self.z, self.y / self.z)
+            }
+        }
+
+        impl Vec4 {
+            #[inline]
+            pub fn truncate(self) -> Vec3 {
+                Vec3::new(self.x, self.y, self.z)
+            }
+
+            /// Homogeneous 3D point to cartesian, divide by `w`.
+            #[inline]
+            pub fn project(self) -> Vec3 {
+                Vec3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+            }
+        }
+
+        impl From<::gk_math::base::vector::Vector<#scalar, 2>> for Vec2 {
+            fn from(item: ::gk_math::base::vector::Vector<#scalar, 2>) -> Self {
+                Vec2::from(item.data)
+            }
+        }
+
+        impl From<Vec2> for ::gk_math::base::vector::Vector<#scalar, 2> {
+            fn from(item: Vec2) -> Self {
+                ::gk_math::base::vector::Vector::new(item.into())
+            }
+        }
+
+        impl From<::gk_math::base::vector::Vector<#scalar, 3>> for Vec3 {
+            fn from(item: ::gk_math::base::vector::Vector<#scalar, 3>) -> Self {
+                Vec3::from(item.data)
+            }
+        }
+
+        impl From<Vec3> for ::gk_math::base::vector::Vector<#scalar, 3> {
+            fn from(item: Vec3) -> Self {
+                ::gk_math::base::vector::Vector::new(item.into())
+            }
+        }
+
+        impl From<::gk_math::base::vector::Vector<#scalar, 4>> for Vec4 {
+            fn from(item: ::gk_math::base::vector::Vector<#scalar, 4>) -> Self {
+                Vec4::from(item.data)
+            }
+        }
+
+        impl From<Vec4> for ::gk_math::base::vector::Vector<#scalar, 4> {
+            fn from(item: Vec4) -> Self {
+                ::gk_math::base::vector::Vector::new(item.into())
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
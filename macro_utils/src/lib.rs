@@ -4,15 +4,38 @@ use vector::vector_math_impl;
 mod tool;
 use tool::mat_vec_mul_impl;
 
+mod family;
+use family::define_math_family_impl;
+
 use proc_macro::*;
 
 
+/// Vector arithmetic for a struct of named fields of one scalar type, or of
+/// one type parameter, e.g. `struct Vec3<T> { x: T, y: T, z: T }`.
+///
+/// The expansion uses the numeric traits of `gk_math::base::num`. Every impl is
+/// bounded on what it needs of the scalar, e.g. `Neg` for negation and `Real`
+/// for `length` and `normalized`, so an impl the scalar cannot support is simply
+/// unavailable. For a type parameter there is no `scalar * vector` product.
 #[proc_macro_derive(VectorMath)]
 pub fn vector_vector_drive(input: TokenStream) -> TokenStream {
     vector_math_impl(input)
 }
 
+/// Implement `Mul` between a `Mat` type alias and vector types.
+///
+/// `Vec3` generates `Mat * Vec3 -> Vec3`, `Vec4 -> Vec3` names a different output
+/// for non-square matrices, `row(Vec3 -> Vec4)` generates `Vec3 * Mat -> Vec4` and
+/// `ref` adds the same products for reference operands. The element type must
+/// implement `gk_math::base::num::Zero`.
 #[proc_macro_attribute]
 pub fn mat_vec_mul(input: TokenStream, item: TokenStream) -> TokenStream {
     mat_vec_mul_impl(input, item)
+}
+
+/// Define `Vec2`-`Vec4`, `Mat2`-`Mat4`, `Mat3x4` and their products for a scalar
+/// type, a primitive or any type implementing the `gk_math::base::num` traits.
+#[proc_macro]
+pub fn define_math_family(input: TokenStream) -> TokenStream {
+    define_math_family_impl(input)
 }
\ No newline at end of file
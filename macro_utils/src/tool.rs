@@ -1,71 +1,358 @@
 use proc_macro::*;
 use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::*;
 
+/// `In` or `In -> Out`, the output type defaults to the input type.
+struct Signature {
+    input: syn::Type,
+    output: Option<syn::Type>,
+}
+
+enum Product {
+    /// `Mat * In -> Out`
+    Col(Signature),
+    /// `In * Mat -> Out`, written `row(In -> Out)`
+    Row(Signature),
+    /// also generate `&Mat * &In`, written `ref`
+    Ref,
+}
+
+struct MatVecMulArgs {
+    products: Punctuated<Product, Token![,]>,
+}
+
+impl Parse for Signature {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let input_ty = parse_vector_type(input)?;
+        let output = if input.peek(Token![->]) {
+            input.parse::<Token![->]>()?;
+            Some(parse_vector_type(input)?)
+        } else {
+            None
+        };
+        Ok(Signature { input: input_ty, output })
+    }
+}
+
+fn parse_vector_type(input: ParseStream) -> Result<syn::Type> {
+    input.parse().map_err(|err| {
+        syn::Error::new(err.span(), "expected vector type, e.g. `Vec3` or `Vec4 -> Vec3`")
+    })
+}
+
+impl Parse for Product {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![ref]) {
+            input.parse::<Token![ref]>()?;
+            return Ok(Product::Ref);
+        }
+
+        if input.peek(syn::Ident) && input.peek2(token::Paren) {
+            let ident: syn::Ident = input.fork().parse()?;
+            if ident == "row" {
+                input.parse::<syn::Ident>()?;
+                let content;
+                parenthesized!(content in input);
+                let signature: Signature = content.parse()?;
+                if !content.is_empty() {
+                    return Err(content.error("unexpected tokens after row product signature"));
+                }
+                return Ok(Product::Row(signature));
+            }
+        }
+
+        Ok(Product::Col(input.parse()?))
+    }
+}
+
+impl Parse for MatVecMulArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(MatVecMulArgs {
+            products: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
 pub fn mat_vec_mul_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
-    println!("impl Mul<{}> for {}", attr, item);
-    let vec_ty = parse_macro_input!(attr as syn::Ident);
+    if attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "expected vector type, e.g. #[mat_vec_mul(Vec3)]"
+        )
+        .to_compile_error()
+        .into();
+    }
 
-    let item = parse_macro_input!(item as ItemType);
+    let args = parse_macro_input!(attr as MatVecMulArgs);
 
-    let mat_ty = &item.ident;
+    let item = match parse_macro_input!(item as Item) {
+        Item::Type(item) => item,
+        item => {
+            return syn::Error::new_spanned(
+                item,
+                "mat_vec_mul can only be applied to a type alias"
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
 
-    println!("{}", quote!{ #vec_ty, #mat_ty}.to_string());
+    match expand_mat_vec_mul(&args, &item) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
 
-    let generics = extract_mat_generics(&item.ty)
-        .expect("Expected Mat<T, ROWS, COLS> type");
-    
-    let (_elem_ty, _rows, cols) = generics;
+fn expand_mat_vec_mul(args: &MatVecMulArgs, item: &ItemType) -> Result<proc_macro2::TokenStream> {
+    let (elem_ty, rows, cols) = extract_mat_generics(&item.ty)?;
 
-    let cols: usize = quote!{#cols}.to_string().parse().unwrap();
+    let by_ref = args.products.iter().any(|product| matches!(product, Product::Ref));
 
-    let index = (0..cols).collect::<Vec<usize>>();
+    let mut impls = Vec::new();
+    for product in &args.products {
+        match product {
+            Product::Col(signature) => {
+                check_signature(signature, &cols, &rows)?;
+                impls.push(col_product(item, signature, &elem_ty, &rows, &cols, by_ref));
+            }
+            Product::Row(signature) => {
+                check_signature(signature, &rows, &cols)?;
+                impls.push(row_product(item, signature, &elem_ty, &rows, &cols, by_ref));
+            }
+            Product::Ref => {}
+        }
+    }
 
-    let mut vec_ty_mul = Vec::new();
-    vec_ty_mul.resize(cols, &vec_ty);
+    if impls.is_empty() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "expected at least one vector type, e.g. #[mat_vec_mul(Vec3, ref)]"
+        ));
+    }
 
-    let expanded = quote! {
+    Ok(quote! {
         #item
 
-        impl std::ops::Mul<#vec_ty> for #mat_ty {
-            type Output = #vec_ty;
-            fn mul(self, rhs: #vec_ty) -> Self::Output {
-                #vec_ty::new(
-                    #(#vec_ty_mul::from(self[#index]).dot(&rhs)),*
-                )
+        #(#impls)*
+    })
+}
+
+/// A signature without an explicit output reuses the input type, which is only
+/// possible when the product keeps the number of components.
+fn check_signature(signature: &Signature, input_dim: &syn::Expr, output_dim: &syn::Expr) -> Result<()> {
+    if signature.output.is_some() {
+        return Ok(());
+    }
+
+    if let (Some(input_value), Some(output_value)) = (literal_dimension(input_dim), literal_dimension(output_dim))
+        && input_value != output_value
+    {
+        let input_ty = &signature.input;
+        return Err(syn::Error::new_spanned(
+            input_ty,
+            format!(
+                "product maps {} components to {}, name the output type with `{} -> VecN`",
+                input_value,
+                output_value,
+                quote!(#input_ty)
+            )
+        ));
+    }
+
+    Ok(())
+}
+
+fn col_product(
+    item: &ItemType,
+    signature: &Signature,
+    elem_ty: &syn::Type,
+    rows: &syn::Expr,
+    cols: &syn::Expr,
+    by_ref: bool,
+) -> proc_macro2::TokenStream {
+    let mat_ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let mat_ty = quote!(#mat_ident #ty_generics);
+    let where_clause = elem_where_clause(where_clause, elem_ty);
+
+    let in_ty = &signature.input;
+    let out_ty = signature.output.as_ref().unwrap_or(in_ty);
+
+    let body = quote! {
+        let mut out = [<#elem_ty as ::gk_math::base::num::Zero>::zero(); #rows];
+        for i in 0..(#rows) {
+            out[i] = (0..(#cols)).fold(out[i], |sum, j| sum + self.data[i][j] * rhs[j]);
+        }
+        <#out_ty>::from(out)
+    };
+
+    let ref_impl = if by_ref {
+        let ref_generics = with_lifetime(&item.generics);
+        let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+        quote! {
+            impl #ref_impl_generics std::ops::Mul<&'mat_vec_mul #in_ty> for &'mat_vec_mul #mat_ty #where_clause {
+                type Output = #out_ty;
+                fn mul(self, rhs: &'mat_vec_mul #in_ty) -> Self::Output {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl #impl_generics std::ops::Mul<#in_ty> for #mat_ty #where_clause {
+            type Output = #out_ty;
+            fn mul(self, rhs: #in_ty) -> Self::Output {
+                #body
             }
         }
+
+        #ref_impl
+    }
+}
+
+fn row_product(
+    item: &ItemType,
+    signature: &Signature,
+    elem_ty: &syn::Type,
+    rows: &syn::Expr,
+    cols: &syn::Expr,
+    by_ref: bool,
+) -> proc_macro2::TokenStream {
+    let mat_ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let mat_ty = quote!(#mat_ident #ty_generics);
+    let where_clause = elem_where_clause(where_clause, elem_ty);
+
+    let in_ty = &signature.input;
+    let out_ty = signature.output.as_ref().unwrap_or(in_ty);
+
+    let body = quote! {
+        let mut out = [<#elem_ty as ::gk_math::base::num::Zero>::zero(); #cols];
+        for j in 0..(#cols) {
+            out[j] = (0..(#rows)).fold(out[j], |sum, i| sum + self[i] * rhs.data[i][j]);
+        }
+        <#out_ty>::from(out)
     };
 
-    TokenStream::from(expanded)
-}
-
-fn extract_mat_generics(ty: &Type) -> Option<(syn::Type, syn::Expr, syn::Expr)> {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "Mat" {
-                if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                    let mut args_iter = args.args.iter();
-                    
-                    let elem_type = match args_iter.next()? {
-                        GenericArgument::Type(ty) => ty.clone(),
-                        _ => return None,
-                    };
-                    
-                    let rows = match args_iter.next()? {
-                        GenericArgument::Const(expr) => expr.clone(),
-                        _ => return None,
-                    };
-                    
-                    let cols = match args_iter.next()? {
-                        GenericArgument::Const(expr) => expr.clone(),
-                        _ => return None,
-                    };
-                    
-                    return Some((elem_type, rows, cols));
+    let ref_impl = if by_ref {
+        let ref_generics = with_lifetime(&item.generics);
+        let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+        quote! {
+            impl #ref_impl_generics std::ops::Mul<&'mat_vec_mul #mat_ty> for &'mat_vec_mul #in_ty #where_clause {
+                type Output = #out_ty;
+                fn mul(self, rhs: &'mat_vec_mul #mat_ty) -> Self::Output {
+                    #body
                 }
             }
         }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl #impl_generics std::ops::Mul<#mat_ty> for #in_ty #where_clause {
+            type Output = #out_ty;
+            fn mul(self, rhs: #mat_ty) -> Self::Output {
+                #body
+            }
+        }
+
+        #ref_impl
+    }
+}
+
+fn with_lifetime(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote!('mat_vec_mul));
+    generics
+}
+
+fn elem_where_clause(where_clause: Option<&WhereClause>, elem_ty: &syn::Type) -> WhereClause {
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    where_clause.predicates.push(parse_quote! {
+        #elem_ty: Copy
+            + ::gk_math::base::num::Zero
+            + std::ops::Mul<Output = #elem_ty>
+    });
+    where_clause
+}
+
+fn extract_mat_generics(ty: &syn::Type) -> Result<(syn::Type, syn::Expr, syn::Expr)> {
+    let not_mat = || syn::Error::new_spanned(
+        ty,
+        "mat_vec_mul expects a type alias of the form `Mat<T, ROWS, COLS>`"
+    );
+
+    let segment = match ty {
+        syn::Type::Path(type_path) => type_path.path.segments.last().ok_or_else(not_mat)?,
+        _ => return Err(not_mat()),
+    };
+
+    if segment.ident != "Mat" {
+        return Err(not_mat());
+    }
+
+    let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args,
+        _ => return Err(not_mat()),
+    };
+
+    if args.args.len() != 3 {
+        return Err(syn::Error::new_spanned(
+            &args.args,
+            format!("expected 3 generic arguments `<T, ROWS, COLS>`, found {}", args.args.len())
+        ));
+    }
+
+    let mut args_iter = args.args.iter();
+
+    let elem_type = match args_iter.next() {
+        Some(GenericArgument::Type(ty)) => ty.clone(),
+        Some(arg) => return Err(syn::Error::new_spanned(arg, "expected element type")),
+        None => unreachable!(),
+    };
+
+    let rows = extract_dimension(args_iter.next().unwrap())?;
+    let cols = extract_dimension(args_iter.next().unwrap())?;
+
+    Ok((elem_type, rows, cols))
+}
+
+fn extract_dimension(arg: &GenericArgument) -> Result<syn::Expr> {
+    match arg {
+        GenericArgument::Const(expr) => Ok(expr.clone()),
+        // a named const such as `N` is parsed as a type path by syn
+        GenericArgument::Type(syn::Type::Path(type_path))
+            if type_path.qself.is_none()
+                && type_path.path.segments.iter().all(|segment| segment.arguments.is_none()) =>
+        {
+            Ok(Expr::Path(ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path: type_path.path.clone(),
+            }))
+        }
+        GenericArgument::Type(ty) => Err(syn::Error::new_spanned(
+            ty,
+            "expected matrix dimension, found type"
+        )),
+        arg => Err(syn::Error::new_spanned(arg, "expected matrix dimension")),
+    }
+}
+
+/// Value of a dimension written as an integer literal, `None` for const expressions.
+fn literal_dimension(expr: &syn::Expr) -> Option<usize> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse::<usize>().ok(),
+        Expr::Block(block) if block.block.stmts.len() == 1 => match &block.block.stmts[0] {
+            Stmt::Expr(inner, None) => literal_dimension(inner),
+            _ => None,
+        },
+        _ => None,
     }
-    None
 }
\ No newline at end of file
@@ -3,8 +3,6 @@ use quote::quote;
 use syn::*;
 
 pub fn vector_math_impl(input: TokenStream) -> TokenStream {
-    println!("{}", input);
-
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
     
@@ -35,9 +33,10 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
 
     let field_count = field_types.len();
     let field_index = (0..field_count).collect::<Vec<usize>>();
+    let tuple_index = (0..field_count).map(syn::Index::from).collect::<Vec<_>>();
 
     // check field is not zero
-    if field_types.len() == 0 {
+    if field_types.is_empty() {
         return syn::Error::new_spanned(
             name,
             format!("no field in structure: {}", name)
@@ -47,26 +46,15 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
     }
     
     let elem_ty: &syn::Type = field_types[0];
+    let elem_ty_str = quote!(#elem_ty).to_string();
+
+    // a struct generic over its field type, e.g. struct Vec3<T> { x: T, y: T, z: T }
+    let is_generic = input.generics.type_params().any(|param| param.ident == elem_ty_str);
 
     // check field type
     for field_type in &field_types {
         let type_str = quote!(#field_type).to_string();
-        let primitive_types = [
-            "i8", "i16", "i32", "i64", "i128", "isize",
-            "u8", "u16", "u32", "u64", "u128", "usize",
-            "f32", "f64",
-        ];
-        
-        if !primitive_types.contains(&type_str.as_str()) {
-            return syn::Error::new_spanned(
-                field_type,
-                format!("field must be primitive type, except: {}", type_str)
-            )
-            .to_compile_error()
-            .into();
-        }
- 
-        if type_str != quote!(#elem_ty).to_string() {
+        if type_str != elem_ty_str {
             return  syn::Error::new_spanned(
                 field_type, 
                 format!("type must be same type {}", type_str)
@@ -76,13 +64,21 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
         }
     }
 
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates: Vec<_> = where_clause.iter().flat_map(|clause| clause.predicates.iter()).collect();
+    // every impl requires what it uses of the element type, the binder defers
+    // the check for a concrete type so an unmet bound only hides the impl
+    let bound = |traits: proc_macro2::TokenStream| quote!(where #(#predicates,)* for<'vector_math> #elem_ty: #traits);
+    let ty = quote!(#name #ty_generics);
+
     // cross product impl generate
     let cross_impl = if field_count == 3 {
+        let bound = bound(quote!(Copy + std::ops::Mul<Output = #elem_ty> + std::ops::Sub<Output = #elem_ty>));
         quote!{
-            impl #name {         
+            impl #impl_generics #ty #bound {
                 #[inline]
                 pub fn cross(a: &Self, b: &Self) -> Self {
-                    #name::new(
+                    Self::new(
                         a[1] * b[2] - a[2] * b[1],
                         a[2] * b[0] - a[0] * b[2],
                         a[0] * b[1] - a[1] * b[0],
@@ -94,9 +90,133 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
         quote!()
     };
 
+    // length and normalization only exist for real fields
+    let float_impl = {
+        let bound = bound(quote!(::gk_math::base::num::Real));
+        quote!{
+            impl #impl_generics #ty #bound {
+                #[inline]
+                pub fn length(&self) -> #elem_ty {
+                    ::gk_math::base::num::Real::sqrt(self.length_squared())
+                }
+
+                #[inline]
+                pub fn normalized(&self) -> Self {
+                    let length = self.length();
+                    Self {
+                        #(#field_names: self.#field_names / length,)*
+                    }
+                }
+            }
+        }
+    };
+
+    // orthonormal frames need both the cross product and normalization
+    let frame_impl = if field_count == 3 {
+        let bound = bound(quote!(::gk_math::base::num::Real));
+        quote!{
+            impl #impl_generics #ty #bound {
+                /// Two unit vectors completing the direction of `self` to a right-handed
+                /// orthonormal basis `(a, b, self.normalized())`, by the branchless method of
+                /// Duff et al., "Building an Orthonormal Basis, Revisited" (2017).
+                pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+                    let n = self.normalized();
+                    let one = <#elem_ty as ::gk_math::base::num::One>::one();
+                    let zero = <#elem_ty as ::gk_math::base::num::Zero>::zero();
+                    let sign = if n[2] < zero { -one } else { one };
+                    let a = -one / (sign + n[2]);
+                    let b = n[0] * n[1] * a;
+                    (
+                        Self::new(one + sign * n[0] * n[0] * a, sign * b, -sign * n[0]),
+                        Self::new(b, sign + n[1] * n[1] * a, -n[1]),
+                    )
+                }
+
+                /// Orthonormalize `vectors` in place by modified Gram–Schmidt and return the
+                /// rank. A vector that depends on the ones before it is set to zero.
+                pub fn orthonormalize(vectors: &mut [Self]) -> usize {
+                    let zero = <#elem_ty as ::gk_math::base::num::Zero>::zero();
+                    let tolerance = ::gk_math::base::num::Real::sqrt(<#elem_ty as ::gk_math::base::num::Real>::epsilon());
+                    let mut rank = 0;
+                    for i in 0..vectors.len() {
+                        let norm = vectors[i].length();
+                        let mut v = vectors[i];
+                        for j in 0..i {
+                            let q = vectors[j];
+                            v = v - q * q.dot(&v);
+                        }
+                        let length = v.length();
+                        if length == zero || length <= tolerance * norm {
+                            vectors[i] = Self::new(zero, zero, zero);
+                        } else {
+                            vectors[i] = v / length;
+                            rank += 1;
+                        }
+                    }
+                    rank
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    // negation only exists for signed fields
+    let neg_impl = {
+        let bound = bound(quote!(std::ops::Neg<Output = #elem_ty>));
+        quote!{
+            impl #impl_generics std::ops::Neg for #ty #bound {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self::new(
+                        #(-self.#field_names),*
+                    )
+                }
+            }
+        }
+    };
+
+    // scalar on the left, the orphan rule forbids it for a type parameter
+    let scalar_mul_impl = if is_generic {
+        quote!()
+    } else {
+        let bound = bound(quote!(std::ops::Mul<Output = #elem_ty>));
+        quote!{
+            impl std::ops::Mul<#name> for #elem_ty #bound {
+                type Output = #name;
+                
+                fn mul(self, rhs: #name) -> Self::Output {
+                    #name::new(
+                        #(self * rhs.#field_names),*
+                    )
+                }
+            }
+        }
+    };
+
+    let copy_bound = bound(quote!(Copy));
+    let arith_bound = bound(quote!(Copy + std::ops::Add<Output = #elem_ty> + std::ops::Mul<Output = #elem_ty>));
+    let zero_bound = bound(quote!(::gk_math::base::num::Zero));
+    let ord_bound = bound(quote!(Copy + PartialOrd));
+    let add_bound = bound(quote!(std::ops::Add<Output = #elem_ty>));
+    let add_assign_bound = bound(quote!(std::ops::AddAssign));
+    let sub_bound = bound(quote!(std::ops::Sub<Output = #elem_ty>));
+    let sub_assign_bound = bound(quote!(std::ops::SubAssign));
+    let mul_bound = bound(quote!(std::ops::Mul<Output = #elem_ty>));
+    let scalar_mul_bound = bound(quote!(Copy + std::ops::Mul<Output = #elem_ty>));
+    let mul_assign_bound = bound(quote!(Copy + std::ops::MulAssign));
+    let div_bound = bound(quote!(std::ops::Div<Output = #elem_ty>));
+    let scalar_div_bound = bound(quote!(Copy + std::ops::Div<Output = #elem_ty>));
+    let div_assign_bound = bound(quote!(Copy + std::ops::DivAssign));
+    let eq_bound = bound(quote!(PartialEq));
+    let partial_ord_bound = bound(quote!(PartialOrd));
+    let debug_bound = bound(quote!(std::fmt::Debug));
+    let display_bound = bound(quote!(std::fmt::Display));
+
     // code generate
     let expanded = quote! {
-        impl #name {
+        impl #impl_generics #ty #where_clause {
             #[inline]
             pub fn new(#(#field_names : #field_types),*) -> Self {
                 Self {
@@ -104,6 +224,17 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
                 }
             }
 
+            pub fn map<F>(self, f: F) -> Self
+            where
+                F: Fn(#elem_ty) -> #elem_ty
+            {
+                Self {
+                    #(#field_names: f(self.#field_names)),*
+                }
+            }
+        }
+
+        impl #impl_generics #ty #arith_bound {
             #[inline]
             pub fn dot(&self, other: &Self) -> #elem_ty {
                 #(self.#field_names * other.#field_names) + *
@@ -115,27 +246,19 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
             }
             
             #[inline]
-            pub fn length(&self) -> #elem_ty {
-                self.length_squared().sqrt()
-            }
-            
-            #[inline]
-            pub fn normalized(&self) -> Self {
-                Self {
-                    #(#field_names: self.#field_names / self.length(),)*
-                }
+            pub fn sum(&self) -> #elem_ty {
+                #(self.#field_names)+*
             }
+        }
 
+        impl #impl_generics #ty #zero_bound {
             #[inline]
             pub fn is_zero(&self) -> bool {
-                #(self.#field_names == <#elem_ty as Default>::default())&&*
-            }
-            
-            #[inline]
-            pub fn sum(&self) -> #elem_ty {
-                #(self.#field_names)+*
+                #(::gk_math::base::num::Zero::is_zero(&self.#field_names))&&*
             }
+        }
 
+        impl #impl_generics #ty #ord_bound {
             pub fn min_element(&self) -> #elem_ty {
                 let mut min = self[0];
                 #(
@@ -155,20 +278,19 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
                 )*
                 max
             }
-
-            pub fn map<F>(self, f: F) -> #name
-            where
-                F: Fn(#elem_ty) -> #elem_ty
-            {
-                #name{
-                    #(#field_names: f(self.#field_names)),*
-                }
-            }
         }
 
         #cross_impl
 
-        impl From<[#elem_ty;#field_count]> for #name {
+        #float_impl
+
+        #frame_impl
+
+        #neg_impl
+
+        #scalar_mul_impl
+
+        impl #impl_generics From<[#elem_ty;#field_count]> for #ty #copy_bound {
             fn from(item: [#elem_ty;#field_count]) -> Self {
                 Self {
                     #(#field_names: item[#field_index]),*
@@ -176,115 +298,115 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl std::ops::Add for #name {
+        impl #impl_generics From<#ty> for [#elem_ty;#field_count] #where_clause {
+            fn from(item: #ty) -> Self {
+                [#(item.#field_names),*]
+            }
+        }
+
+        impl #impl_generics From<(#(#field_types,)*)> for #ty #where_clause {
+            fn from(item: (#(#field_types,)*)) -> Self {
+                Self {
+                    #(#field_names: item.#tuple_index),*
+                }
+            }
+        }
+
+        impl #impl_generics From<#ty> for (#(#field_types,)*) #where_clause {
+            fn from(item: #ty) -> Self {
+                (#(item.#field_names,)*)
+            }
+        }
+
+        impl #impl_generics std::ops::Add for #ty #add_bound {
             type Output = Self;
             
             fn add(self, rhs: Self) -> Self::Output {
-                #name::new(
+                Self::new(
                     #(self.#field_names + rhs.#field_names),*
                 )
             }
         }
         
-        impl std::ops::AddAssign for #name {
+        impl #impl_generics std::ops::AddAssign for #ty #add_assign_bound {
             fn add_assign(&mut self, rhs: Self) {
                 #(self.#field_names += rhs.#field_names;)*
             }
         }
         
-        impl std::ops::Sub for #name {
+        impl #impl_generics std::ops::Sub for #ty #sub_bound {
             type Output = Self;
             
             fn sub(self, rhs: Self) -> Self::Output {
-                #name::new(
+                Self::new(
                     #(self.#field_names - rhs.#field_names),*
                 )
             }
         }
         
-        impl std::ops::SubAssign for #name {
+        impl #impl_generics std::ops::SubAssign for #ty #sub_assign_bound {
             fn sub_assign(&mut self, rhs: Self) {
                 #(self.#field_names -= rhs.#field_names;)*
             }
         }
         
-        impl std::ops::Neg for #name {
-            type Output = Self;
-            
-            fn neg(self) -> Self::Output {
-                #name::new(
-                    #(-self.#field_names),*
-                )
-            }
-        }
-        
-        impl std::ops::Mul<#elem_ty> for #name {
+        impl #impl_generics std::ops::Mul<#elem_ty> for #ty #scalar_mul_bound {
             type Output = Self;
             
             fn mul(self, rhs: #elem_ty) -> Self::Output {
-                #name::new(
+                Self::new(
                     #(self.#field_names * rhs),*
                 )
             }
         }
         
-        impl std::ops::MulAssign<#elem_ty> for #name {
+        impl #impl_generics std::ops::MulAssign<#elem_ty> for #ty #mul_assign_bound {
             fn mul_assign(&mut self, rhs: #elem_ty) {
                 #(self.#field_names *= rhs;)*
             }
         }
 
-        impl std::ops::Mul<#name> for #elem_ty {
-            type Output = #name;
-            
-            fn mul(self, rhs: #name) -> Self::Output {
-                #name::new(
-                    #(self * rhs.#field_names),*
-                )
-            }
-        }
-
-        impl std::ops::Mul<#name> for #name {
-            type Output = #name;
-            fn mul(self, rhs: #name) -> Self::Output {
-                #name::new(
+        impl #impl_generics std::ops::Mul<#ty> for #ty #mul_bound {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self::new(
                     #(self.#field_names * rhs.#field_names),*
                 )
             }
         }
 
-        impl std::ops::Div<#elem_ty> for #name {
+        impl #impl_generics std::ops::Div<#elem_ty> for #ty #scalar_div_bound {
             type Output = Self;
             
             fn div(self, rhs: #elem_ty) -> Self::Output {
-                #name::new(
+                Self::new(
                     #(self.#field_names / rhs),*
                 )
             }
         }
         
-        impl std::ops::DivAssign<#elem_ty> for #name {
+        impl #impl_generics std::ops::DivAssign<#elem_ty> for #ty #div_assign_bound {
             fn div_assign(&mut self, rhs: #elem_ty) {
                 #(self.#field_names /= rhs;)*
             }
         }
 
-        impl std::ops::Div<#name> for #name {
+        impl #impl_generics std::ops::Div<#ty> for #ty #div_bound {
             type Output = Self;
-            fn div(self, rhs: #name) -> Self::Output {
-                #name::new(
+            fn div(self, rhs: Self) -> Self::Output {
+                Self::new(
                     #(self.#field_names / rhs.#field_names),*
                 )
             }
         }
         
-        impl PartialEq for #name {
+        impl #impl_generics PartialEq for #ty #eq_bound {
             fn eq(&self, other: &Self) -> bool {
                 #(self.#field_names == other.#field_names)&&*
             }
         }
         
-        impl PartialOrd for #name {
+        impl #impl_generics PartialOrd for #ty #partial_ord_bound {
             fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
                 
                 let mut result = std::cmp::Ordering::Equal;
@@ -328,7 +450,7 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
             }
         }
         
-        impl std::fmt::Debug for #name {
+        impl #impl_generics std::fmt::Debug for #ty #debug_bound {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(f, "{} [", stringify!(#name))?;
                 #(
@@ -338,7 +460,7 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
             }
         }
         
-        impl std::fmt::Display for #name {
+        impl #impl_generics std::fmt::Display for #ty #display_bound {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 write!(f, "[")?;
                 #(
@@ -348,7 +470,7 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
             }
         }
         
-        impl std::ops::Index<usize> for #name {
+        impl #impl_generics std::ops::Index<usize> for #ty #where_clause {
             type Output = #elem_ty;
             
             fn index(&self, index: usize) -> &Self::Output {
@@ -361,7 +483,7 @@ pub fn vector_math_impl(input: TokenStream) -> TokenStream {
             }
         }
         
-        impl std::ops::IndexMut<usize> for #name {
+        impl #impl_generics std::ops::IndexMut<usize> for #ty #where_clause {
             fn index_mut(&mut self, index: usize) -> &mut Self::Output {
                 match index {
                     #(
@@ -0,0 +1,97 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use gk_math::base::num::{One, Ring, Zero};
+
+/// Q16.16 fixed-point number.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    pub fn from_int(value: i32) -> Self {
+        Fixed(value << 16)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Fixed(((self.0 as i64 * rhs.0 as i64) >> 16) as i32)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Fixed((((self.0 as i64) << 16) / rhs.0 as i64) as i32)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(-self.0)
+    }
+}
+
+impl Zero for Fixed {
+    fn zero() -> Self {
+        Fixed(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Fixed {
+    fn one() -> Self {
+        Fixed::from_int(1)
+    }
+}
+
+impl Ring for Fixed {}
+
+// no Mat or Vector in scope
+mod family {
+    use super::Fixed;
+
+    macro_utils::define_math_family!(Fixed);
+}
+
+fn main() {
+    use family::{Mat3, Vec3};
+
+    let f = Fixed::from_int;
+    let v = Vec3::new(f(1), f(2), f(3));
+    let w = Vec3::from([f(4), f(5), f(6)]);
+    assert_eq!(v.dot(&w), f(32));
+    assert_eq!(Vec3::cross(&v, &w), Vec3::new(f(-3), f(6), f(-3)));
+    assert_eq!(-v * f(2), Vec3::new(f(-2), f(-4), f(-6)));
+    assert_eq!(f(2) * v, v + v);
+
+    let m = Mat3::identity() * f(2);
+    assert_eq!(m * v, v * f(2));
+    assert_eq!(v.extend(f(2)).project(), Vec3::new(Fixed(1 << 15), f(1), Fixed(3 << 15)));
+
+    let vector: gk_math::base::vector::Vector<Fixed, 3> = v.into();
+    assert_eq!(Vec3::from(vector), v);
+}
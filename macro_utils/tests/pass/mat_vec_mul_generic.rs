@@ -0,0 +1,41 @@
+use macro_utils::mat_vec_mul;
+use std::ops::Index;
+
+pub struct Mat<T, const R: usize, const C: usize> {
+    pub data: [[T; C]; R],
+}
+
+pub struct Column<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> From<[T; N]> for Column<T, N> {
+    fn from(item: [T; N]) -> Self {
+        Column(item)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Column<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+const ROWS: usize = 2;
+const COLS: usize = ROWS + 1;
+
+#[mat_vec_mul(Column<T, COLS> -> Column<T, ROWS>, row(Column<T, ROWS> -> Column<T, COLS>), ref)]
+pub type Mat2x3<T> = Mat<T, ROWS, { ROWS + 1 }>;
+
+fn main() {
+    let m: Mat2x3<i32> = Mat { data: [[1, 2, 3], [4, 5, 6]] };
+
+    let v = &m * &Column([1, 1, 1]);
+    assert_eq!((v[0], v[1]), (6, 15));
+
+    let v = &Column([1, 1]) * &m;
+    assert_eq!((v[0], v[1], v[2]), (5, 7, 9));
+
+    let v = m * Column([1, 0, 0]);
+    assert_eq!((v[0], v[1]), (1, 4));
+}
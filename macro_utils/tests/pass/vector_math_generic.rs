@@ -0,0 +1,37 @@
+use macro_utils::VectorMath;
+
+#[derive(Clone, Copy, VectorMath)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+#[derive(Clone, Copy, VectorMath)]
+pub struct Pair<T>
+where
+    T: Copy,
+{
+    pub a: T,
+    pub b: T,
+}
+
+fn main() {
+    let v = Vec3::new(1, 2, 3);
+    let w = Vec3::from([4, 5, 6]);
+    assert_eq!(v.dot(&w), 32);
+    assert_eq!(Vec3::cross(&v, &w), Vec3::new(-3, 6, -3));
+    assert_eq!(-(v + w) * 2, Vec3::new(-10, -14, -18));
+    assert_eq!(w.max_element(), 6);
+
+    // length and normalization for any gk_math::base::num::Real
+    let u = Vec3::new(3.0f64, 0.0, 4.0);
+    assert_eq!(u.length(), 5.0);
+    assert_eq!(u.normalized(), Vec3::new(0.6, 0.0, 0.8));
+
+    // unsigned fields have no negation but everything else
+    let p = Pair::new(2u8, 3u8);
+    let t: (u8, u8) = (p * p).into();
+    assert_eq!(t, (4, 9));
+    assert!(!p.is_zero());
+}
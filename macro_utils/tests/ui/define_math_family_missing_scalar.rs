@@ -0,0 +1,5 @@
+use macro_utils::define_math_family;
+
+define_math_family!();
+
+fn main() {}
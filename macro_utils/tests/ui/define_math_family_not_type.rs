@@ -0,0 +1,5 @@
+use macro_utils::define_math_family;
+
+define_math_family!(1.0);
+
+fn main() {}
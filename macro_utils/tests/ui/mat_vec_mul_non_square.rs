@@ -0,0 +1,11 @@
+use macro_utils::mat_vec_mul;
+
+#[allow(dead_code)]
+pub struct Mat<T, const R: usize, const C: usize> {
+    pub data: [[T; C]; R],
+}
+
+#[mat_vec_mul(Vec3)]
+pub type Mat3x4 = Mat<f32, 3, 4>;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use macro_utils::VectorMath;
+
+#[derive(VectorMath)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use macro_utils::VectorMath;
+
+#[derive(VectorMath)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f64,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use macro_utils::VectorMath;
+
+#[derive(VectorMath)]
+pub struct Empty {}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use macro_utils::VectorMath;
+
+#[derive(Clone, Copy, VectorMath)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+fn main() {
+    let _ = Vec2::new(3, 4).length();
+}
@@ -0,0 +1,6 @@
+use macro_utils::VectorMath;
+
+#[derive(VectorMath)]
+pub struct Vec2(f32, f32);
+
+fn main() {}
@@ -0,0 +1,429 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+use std::fmt;
+
+use crate::base::matrix::Mat;
+use crate::base::num::{Field, One, Real, Ring, Zero};
+use crate::base::vector::Vector;
+
+/// Dual number `value + grad·ε` with `ε² = 0` for forward-mode automatic
+/// differentiation, `grad[i]` carries the partial derivative with respect to
+/// the `i`-th input.
+///
+/// It is a `Real`, so it can be the element of `Mat`, `Vector` and the
+/// generic `VectorMath` structs. The ordering only looks at `value`, two
+/// duals with equal values but different gradients are unordered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DualN<T, const N: usize> {
+    pub value: T,
+    pub grad: [T; N],
+}
+
+/// Dual number with a single derivative.
+pub type Dual<T> = DualN<T, 1>;
+
+impl<T, const N: usize> DualN<T, N>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn new(value: T, grad: [T; N]) -> Self {
+        Self { value, grad }
+    }
+
+    /// Zero gradient.
+    #[inline]
+    pub fn constant(value: T) -> Self {
+        Self { value, grad: [T::zero(); N] }
+    }
+
+    /// The `index`-th input, its gradient is the unit vector `e_index`.
+    pub fn variable(value: T, index: usize) -> Self {
+        assert!(index < N, "Index out of range");
+        Self { value, grad: std::array::from_fn(|i| if i == index { T::one() } else { T::zero() }) }
+    }
+
+    /// Seed every component of `values` as an input.
+    pub fn variables(values: [T; N]) -> [Self; N] {
+        std::array::from_fn(|i| Self::variable(values[i], i))
+    }
+
+    /// `f(self)` given `f(value)` and `f'(value)`.
+    #[inline]
+    fn chain(&self, value: T, deriv: T) -> Self {
+        Self { value, grad: self.grad.map(|g| g * deriv) }
+    }
+
+    pub fn sqrt(self) -> Self {
+        let s = self.value.sqrt();
+        self.chain(s, T::one() / (s + s))
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.value.exp();
+        self.chain(e, e)
+    }
+
+    pub fn ln(self) -> Self {
+        self.chain(self.value.ln(), T::one() / self.value)
+    }
+
+    pub fn sin(self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    pub fn tan(self) -> Self {
+        let t = self.value.sin() / self.value.cos();
+        self.chain(t, T::one() + t * t)
+    }
+
+    /// The derivative at zero is taken from the right.
+    pub fn abs(self) -> Self {
+        if self.value < T::zero() { -self } else { self }
+    }
+
+    /// Four-quadrant `atan2(self, x)`.
+    pub fn atan2(self, x: Self) -> Self {
+        let scale = T::one() / (x.value * x.value + self.value * self.value);
+        Self {
+            value: self.value.atan2(x.value),
+            grad: std::array::from_fn(|i| (x.value * self.grad[i] - self.value * x.grad[i]) * scale),
+        }
+    }
+
+    pub fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        let n = T::from_f64(n as f64);
+        self.chain(self.value.powf(n), n * self.value.powf(n - T::one()))
+    }
+
+    /// `self^n`, the `ln(self)` term is only formed when the exponent has a
+    /// gradient, so constant exponents work for negative bases.
+    pub fn powf(self, n: Self) -> Self {
+        let value = self.value.powf(n.value);
+        let mut result = self.chain(value, n.value * self.value.powf(n.value - T::one()));
+        if n.grad.iter().any(|g| !g.is_zero()) {
+            let ln = self.value.ln();
+            for i in 0..N {
+                result.grad[i] = result.grad[i] + value * ln * n.grad[i];
+            }
+        }
+        result
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn deriv(&self) -> T {
+        self.grad[0]
+    }
+}
+
+/// `f'(x)` of a scalar function.
+pub fn derivative<T, F>(f: F, x: T) -> T
+where
+    T: Real,
+    F: Fn(Dual<T>) -> Dual<T>,
+{
+    f(Dual::variable(x, 0)).deriv()
+}
+
+/// `∇f(x)` of a scalar function of `N` variables, in one evaluation.
+pub fn gradient<T, F, const N: usize>(f: F, x: &Vector<T, N>) -> Vector<T, N>
+where
+    T: Real,
+    F: Fn(&Vector<DualN<T, N>, N>) -> DualN<T, N>,
+{
+    Vector::new(f(&Vector::new(DualN::variables(x.data))).grad)
+}
+
+/// `f(x)` and its Jacobian `J[i][j] = ∂fᵢ/∂xⱼ`, in one evaluation.
+pub fn value_and_jacobian<T, F, const M: usize, const N: usize>(f: F, x: &Vector<T, N>) -> (Vector<T, M>, Mat<T, M, N>)
+where
+    T: Real,
+    F: Fn(&Vector<DualN<T, N>, N>) -> Vector<DualN<T, N>, M>,
+{
+    let y = f(&Vector::new(DualN::variables(x.data)));
+    (Vector::from_fn(|i| y.data[i].value), Mat::from_fn(|i, j| y.data[i].grad[j]))
+}
+
+/// Jacobian `J[i][j] = ∂fᵢ/∂xⱼ` of `f` at `x`.
+pub fn jacobian<T, F, const M: usize, const N: usize>(f: F, x: &Vector<T, N>) -> Mat<T, M, N>
+where
+    T: Real,
+    F: Fn(&Vector<DualN<T, N>, N>) -> Vector<DualN<T, N>, M>,
+{
+    value_and_jacobian(f, x).1
+}
+
+impl<T, const N: usize> Add for DualN<T, N>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { value: self.value + rhs.value, grad: std::array::from_fn(|i| self.grad[i] + rhs.grad[i]) }
+    }
+}
+
+impl<T, const N: usize> Sub for DualN<T, N>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { value: self.value - rhs.value, grad: std::array::from_fn(|i| self.grad[i] - rhs.grad[i]) }
+    }
+}
+
+impl<T, const N: usize> Mul for DualN<T, N>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value * rhs.value,
+            grad: std::array::from_fn(|i| self.grad[i] * rhs.value + self.value * rhs.grad[i]),
+        }
+    }
+}
+
+impl<T, const N: usize> Div for DualN<T, N>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = self.value / rhs.value;
+        Self { value, grad: std::array::from_fn(|i| (self.grad[i] - value * rhs.grad[i]) / rhs.value) }
+    }
+}
+
+impl<T, const N: usize> Neg for DualN<T, N>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { value: -self.value, grad: self.grad.map(|g| -g) }
+    }
+}
+
+impl<T, const N: usize> Mul<T> for DualN<T, N>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self { value: self.value * rhs, grad: self.grad.map(|g| g * rhs) }
+    }
+}
+
+impl<T, const N: usize> Div<T> for DualN<T, N>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self { value: self.value / rhs, grad: self.grad.map(|g| g / rhs) }
+    }
+}
+
+impl<T, const N: usize> AddAssign for DualN<T, N>
+where
+    T: Real,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T, const N: usize> SubAssign for DualN<T, N>
+where
+    T: Real,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T, const N: usize> MulAssign for DualN<T, N>
+where
+    T: Real,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T, const N: usize> DivAssign for DualN<T, N>
+where
+    T: Real,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T, const N: usize> PartialOrd for DualN<T, N>
+where
+    T: Real,
+{
+    /// By value, `Equal` only when the gradients agree as well.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.value.partial_cmp(&other.value)? {
+            Ordering::Equal if self.grad != other.grad => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<T, const N: usize> Zero for DualN<T, N>
+where
+    T: Real,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.grad.iter().all(|g| g.is_zero())
+    }
+}
+
+impl<T, const N: usize> One for DualN<T, N>
+where
+    T: Real,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T, const N: usize> Ring for DualN<T, N> where T: Real {}
+
+impl<T, const N: usize> Field for DualN<T, N>
+where
+    T: Real,
+{
+    fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+        crate::base::complex::scaled_div(a, b, c, d)
+    }
+}
+
+impl<T, const N: usize> Real for DualN<T, N>
+where
+    T: Real,
+{
+    #[inline]
+    fn epsilon() -> Self {
+        Self::constant(T::epsilon())
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+
+    fn abs(self) -> Self {
+        DualN::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        DualN::sqrt(self)
+    }
+
+    fn is_finite(self) -> bool {
+        self.value.is_finite() && self.grad.iter().all(|g| g.is_finite())
+    }
+
+    fn exp(self) -> Self {
+        DualN::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        DualN::ln(self)
+    }
+
+    fn sin(self) -> Self {
+        DualN::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        DualN::cos(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        DualN::atan2(self, other)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        DualN::powf(self, n)
+    }
+}
+
+impl<T, const N: usize> Default for DualN<T, N>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T, const N: usize> From<T> for DualN<T, N>
+where
+    T: Real,
+{
+    fn from(value: T) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<T, const N: usize> fmt::Display for DualN<T, N>
+where
+    T: Real + fmt::Display,
+{
+    /// `3 + 2ε` for a single derivative, `3 + [2, 0]ε` otherwise.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + ", self.value)?;
+        if N == 1 {
+            return write!(f, "{}ε", self.grad[0]);
+        }
+        write!(f, "[")?;
+        for (i, g) in self.grad.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", g)?;
+        }
+        write!(f, "]ε")
+    }
+}
@@ -0,0 +1,492 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+use std::fmt;
+
+use crate::base::num::{Field, One, Real, Ring, Zero};
+
+/// Operation on the tape, the indices of its inputs with the partial
+/// derivatives of its value with respect to them.
+#[derive(Clone, Copy)]
+struct Node<T> {
+    parents: [(usize, T); 2],
+    arity: usize,
+}
+
+/// Record of the operations on `Var` values for reverse-mode automatic
+/// differentiation.
+///
+/// Every operation appends one node, `Var::gradient` then walks the tape
+/// backwards once, so a gradient costs a small multiple of the function
+/// evaluation whatever the number of inputs.
+pub struct Tape<T> {
+    nodes: RefCell<Vec<Node<T>>>,
+}
+
+impl<T> Tape<T>
+where
+    T: Real,
+{
+    pub fn new() -> Self {
+        Self { nodes: RefCell::new(Vec::new()) }
+    }
+
+    /// New input with value `value`.
+    pub fn var(&self, value: T) -> Var<'_, T> {
+        let index = self.push([(0, T::zero()); 2], 0);
+        Var { value, node: Some((self, index)) }
+    }
+
+    /// Number of recorded nodes, inputs included.
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every node. The borrow checker makes sure no `Var` refers to them.
+    pub fn clear(&mut self) {
+        self.nodes.get_mut().clear();
+    }
+
+    fn push(&self, parents: [(usize, T); 2], arity: usize) -> usize {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { parents, arity });
+        nodes.len() - 1
+    }
+}
+
+impl<T> Default for Tape<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Scalar recorded on a `Tape`, or a constant that is on no tape.
+///
+/// It is a `Real`, so a `Mat<Var<T>, R, C>` expression built from some `Var`
+/// inputs can be differentiated with respect to all of them at once.
+/// Comparisons only look at the value.
+#[derive(Clone, Copy)]
+pub struct Var<'t, T> {
+    value: T,
+    node: Option<(&'t Tape<T>, usize)>,
+}
+
+impl<'t, T> Var<'t, T>
+where
+    T: Real,
+{
+    /// Value with zero derivative, not recorded.
+    #[inline]
+    pub fn constant(value: T) -> Self {
+        Self { value, node: None }
+    }
+
+    #[inline]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Derivatives of `self` with respect to every `Var` recorded before it,
+    /// by one backward sweep over the tape.
+    pub fn gradient(&self) -> Gradient<T> {
+        let Some((tape, index)) = self.node else {
+            return Gradient { adjoints: Vec::new() };
+        };
+        let nodes = tape.nodes.borrow();
+        let mut adjoints = vec![T::zero(); index + 1];
+        adjoints[index] = T::one();
+        for i in (0..=index).rev() {
+            let adjoint = adjoints[i];
+            if adjoint.is_zero() {
+                continue;
+            }
+            let node = &nodes[i];
+            for &(parent, partial) in &node.parents[..node.arity] {
+                adjoints[parent] = adjoints[parent] + partial * adjoint;
+            }
+        }
+        Gradient { adjoints }
+    }
+
+    fn unary(&self, value: T, partial: T) -> Self {
+        match self.node {
+            Some((tape, i)) => Self { value, node: Some((tape, tape.push([(i, partial), (0, T::zero())], 1))) },
+            None => Self::constant(value),
+        }
+    }
+
+    fn binary(&self, other: &Self, value: T, partial: T, other_partial: T) -> Self {
+        match (self.node, other.node) {
+            (Some((tape, i)), Some((other_tape, j))) => {
+                assert!(std::ptr::eq(tape, other_tape), "Vars from different tapes");
+                Self { value, node: Some((tape, tape.push([(i, partial), (j, other_partial)], 2))) }
+            }
+            (Some(_), None) => self.unary(value, partial),
+            (None, Some(_)) => other.unary(value, other_partial),
+            (None, None) => Self::constant(value),
+        }
+    }
+
+    pub fn sqrt(self) -> Self {
+        let s = self.value.sqrt();
+        self.unary(s, T::one() / (s + s))
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.value.exp();
+        self.unary(e, e)
+    }
+
+    pub fn ln(self) -> Self {
+        self.unary(self.value.ln(), T::one() / self.value)
+    }
+
+    pub fn sin(self) -> Self {
+        self.unary(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        self.unary(self.value.cos(), -self.value.sin())
+    }
+
+    pub fn tan(self) -> Self {
+        let t = self.value.sin() / self.value.cos();
+        self.unary(t, T::one() + t * t)
+    }
+
+    /// The derivative at zero is taken from the right.
+    pub fn abs(self) -> Self {
+        let sign = if self.value < T::zero() { -T::one() } else { T::one() };
+        self.unary(self.value.abs(), sign)
+    }
+
+    /// Four-quadrant `atan2(self, x)`.
+    pub fn atan2(self, x: Self) -> Self {
+        let scale = T::one() / (x.value * x.value + self.value * self.value);
+        self.binary(&x, self.value.atan2(x.value), x.value * scale, -self.value * scale)
+    }
+
+    pub fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        let n = T::from_f64(n as f64);
+        self.unary(self.value.powf(n), n * self.value.powf(n - T::one()))
+    }
+
+    /// `self^n`, the `ln(self)` partial is only formed when the exponent is
+    /// recorded, so constant exponents work for negative bases.
+    pub fn powf(self, n: Self) -> Self {
+        let value = self.value.powf(n.value);
+        let partial = n.value * self.value.powf(n.value - T::one());
+        match n.node {
+            Some(_) => self.binary(&n, value, partial, value * self.value.ln()),
+            None => self.unary(value, partial),
+        }
+    }
+}
+
+/// Adjoints of one backward sweep, see `Var::gradient`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient<T> {
+    adjoints: Vec<T>,
+}
+
+impl<T> Gradient<T>
+where
+    T: Real,
+{
+    /// Derivative with respect to `var`, zero for constants and for vars
+    /// recorded after the differentiated one.
+    pub fn wrt(&self, var: &Var<T>) -> T {
+        var.node.and_then(|(_, i)| self.adjoints.get(i).copied()).unwrap_or_else(T::zero)
+    }
+
+    pub fn wrt_all(&self, vars: &[Var<T>]) -> Vec<T> {
+        vars.iter().map(|var| self.wrt(var)).collect()
+    }
+}
+
+/// Value and gradient of a scalar function of `x.len()` variables, recorded on
+/// a fresh tape.
+pub fn gradient<T, F>(f: F, x: &[T]) -> (T, Vec<T>)
+where
+    T: Real,
+    F: for<'t> Fn(&[Var<'t, T>]) -> Var<'t, T>,
+{
+    let tape = Tape::new();
+    let vars: Vec<_> = x.iter().map(|&value| tape.var(value)).collect();
+    let y = f(&vars);
+    (y.value, y.gradient().wrt_all(&vars))
+}
+
+impl<T> Add for Var<'_, T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.binary(&rhs, self.value + rhs.value, T::one(), T::one())
+    }
+}
+
+impl<T> Sub for Var<'_, T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.binary(&rhs, self.value - rhs.value, T::one(), -T::one())
+    }
+}
+
+impl<T> Mul for Var<'_, T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.binary(&rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl<T> Div for Var<'_, T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = self.value / rhs.value;
+        self.binary(&rhs, value, T::one() / rhs.value, -value / rhs.value)
+    }
+}
+
+impl<T> Neg for Var<'_, T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.unary(-self.value, -T::one())
+    }
+}
+
+impl<T> Mul<T> for Var<'_, T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.unary(self.value * rhs, rhs)
+    }
+}
+
+impl<T> Div<T> for Var<'_, T>
+where
+    T: Real,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.unary(self.value / rhs, T::one() / rhs)
+    }
+}
+
+impl<T> AddAssign for Var<'_, T>
+where
+    T: Real,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> SubAssign for Var<'_, T>
+where
+    T: Real,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> MulAssign for Var<'_, T>
+where
+    T: Real,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> DivAssign for Var<'_, T>
+where
+    T: Real,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T> PartialEq for Var<'_, T>
+where
+    T: Real,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> PartialOrd for Var<'_, T>
+where
+    T: Real,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T> Zero for Var<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    /// Only constant zeros, a recorded zero can still carry a derivative.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.node.is_none()
+    }
+}
+
+impl<T> One for Var<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T> Ring for Var<'_, T> where T: Real {}
+
+impl<T> Field for Var<'_, T>
+where
+    T: Real,
+{
+    fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+        crate::base::complex::scaled_div(a, b, c, d)
+    }
+}
+
+impl<T> Real for Var<'_, T>
+where
+    T: Real,
+{
+    #[inline]
+    fn epsilon() -> Self {
+        Self::constant(T::epsilon())
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        Self::constant(T::from_f64(value))
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.value.to_f64()
+    }
+
+    fn abs(self) -> Self {
+        Var::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        Var::sqrt(self)
+    }
+
+    fn is_finite(self) -> bool {
+        self.value.is_finite()
+    }
+
+    fn exp(self) -> Self {
+        Var::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        Var::ln(self)
+    }
+
+    fn sin(self) -> Self {
+        Var::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        Var::cos(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Var::atan2(self, other)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        Var::powf(self, n)
+    }
+}
+
+impl<T> Default for Var<'_, T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T> From<T> for Var<'_, T>
+where
+    T: Real,
+{
+    fn from(value: T) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<T> fmt::Debug for Var<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.node {
+            Some((_, index)) => write!(f, "Var({:?} @ {})", self.value, index),
+            None => write!(f, "Var({:?})", self.value),
+        }
+    }
+}
+
+impl<T> fmt::Display for Var<'_, T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,644 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Rem, RemAssign, Neg};
+use std::str::FromStr;
+use std::fmt;
+
+use crate::base::num::{Integer, One, Ring, Zero};
+use crate::base::rational::Rational;
+
+/// Limb count of the smaller operand from which multiplication switches
+/// from schoolbook to Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Arbitrary-precision signed integer.
+///
+/// The magnitude is stored in little-endian 32-bit limbs without trailing
+/// zero limbs, zero is never negative.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    mag: Vec<u32>,
+}
+
+/// Arbitrary-precision fraction.
+pub type BigRational = Rational<BigInt>;
+
+impl BigInt {
+    fn from_parts(negative: bool, mut mag: Vec<u32>) -> Self {
+        while mag.last() == Some(&0) {
+            mag.pop();
+        }
+        let negative = negative && !mag.is_empty();
+        Self { negative, mag }
+    }
+
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> Self {
+        Self { negative: false, mag: self.mag.clone() }
+    }
+
+    /// Number of bits of the magnitude, zero for zero.
+    pub fn bits(&self) -> u64 {
+        match self.mag.last() {
+            Some(last) => self.mag.len() as u64 * 32 - last.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Truncating division with the remainder taking the sign of `self`, like
+    /// the primitive integers. Panics if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.mag.is_empty(), "BigInt division by zero");
+        let (q, r) = div_rem_mag(&self.mag, &other.mag);
+        (
+            Self::from_parts(self.negative != other.negative, q),
+            Self::from_parts(self.negative, r),
+        )
+    }
+
+    /// Non-negative greatest common divisor.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.mag.is_empty() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Parse an optionally signed number in `radix` 2 to 36.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, &'static str> {
+        if !(2..=36).contains(&radix) {
+            return Err("Radix must be between 2 and 36");
+        }
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if digits.is_empty() {
+            return Err("Invalid digit");
+        }
+
+        // consume as many digits at once as fit in a limb
+        let (chunk, chunk_base) = radix_chunk(radix);
+        let mut mag = Vec::new();
+        let digits: Vec<char> = digits.chars().collect();
+        for part in digits.chunks(chunk) {
+            let mut value = 0u32;
+            for c in part {
+                value = value * radix + c.to_digit(radix).ok_or("Invalid digit")?;
+            }
+            let scale = if part.len() == chunk { chunk_base } else { radix.pow(part.len() as u32) };
+            mul_add_small(&mut mag, scale, value);
+        }
+        Ok(Self::from_parts(negative, mag))
+    }
+
+    /// Format in `radix` 2 to 36 with lowercase digits.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "Radix must be between 2 and 36");
+        if self.mag.is_empty() {
+            return "0".to_string();
+        }
+
+        let (chunk, chunk_base) = radix_chunk(radix);
+        let mut mag = self.mag.clone();
+        let mut digits = Vec::new();
+        while !mag.is_empty() {
+            let mut rem = div_rem_small(&mut mag, chunk_base);
+            for _ in 0..chunk {
+                digits.push(std::char::from_digit(rem % radix, radix).unwrap());
+                rem /= radix;
+                if mag.is_empty() && rem == 0 {
+                    break;
+                }
+            }
+        }
+        if self.negative {
+            digits.push('-');
+        }
+        digits.iter().rev().collect()
+    }
+
+    /// `None` if the value does not fit.
+    pub fn to_i64(&self) -> Option<i64> {
+        let mag = self.to_u128_mag()?;
+        if self.negative {
+            if mag <= i64::MAX as u128 + 1 { Some((mag as i128).wrapping_neg() as i64) } else { None }
+        } else {
+            i64::try_from(mag).ok()
+        }
+    }
+
+    /// Nearest `f64` with ties to even, infinite beyond the range of `f64`.
+    pub fn to_f64(&self) -> f64 {
+        let bits = self.bits();
+        // the top 64 bits with the dropped ones folded into a sticky bit round
+        // like the whole magnitude, `u64 as f64` then rounds correctly
+        let (top, exponent) = if bits <= 64 {
+            (self.mag.iter().rev().fold(0u64, |acc, &limb| (acc << 32) | limb as u64), 0)
+        } else {
+            let shift = bits - 64;
+            let (limbs, offset) = ((shift / 32) as usize, (shift % 32) as u32);
+            let window = shr_bits(&self.mag[limbs..], offset);
+            let top = window[..2].iter().rev().fold(0u64, |acc, &limb| (acc << 32) | limb as u64);
+            let sticky = self.mag[..limbs].iter().any(|&limb| limb != 0) || self.mag[limbs] & ((1 << offset) - 1) != 0;
+            (top | sticky as u64, shift)
+        };
+        let value = top as f64 * 2f64.powi(exponent.min(2048) as i32);
+        if self.negative { -value } else { value }
+    }
+
+    fn to_u128_mag(&self) -> Option<u128> {
+        if self.mag.len() > 4 {
+            return None;
+        }
+        Some(self.mag.iter().rev().fold(0u128, |acc, &limb| (acc << 32) | limb as u128))
+    }
+}
+
+/// Largest number of `radix` digits whose value fits in a limb, and `radix` to that power.
+fn radix_chunk(radix: u32) -> (usize, u32) {
+    let mut chunk = 1;
+    let mut base = radix;
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        chunk += 1;
+    }
+    (chunk, base)
+}
+
+fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// `a - b` for `a >= b`.
+fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, &limb) in a.iter().enumerate() {
+        let diff = limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        result.push(diff as u32);
+        borrow = if diff < 0 { 1 } else { 0 };
+    }
+    while result.last() == Some(&0) {
+        result.pop();
+    }
+    result
+}
+
+/// `acc += x << (32 * shift)`.
+fn add_shifted(acc: &mut Vec<u32>, x: &[u32], shift: usize) {
+    if acc.len() < x.len() + shift {
+        acc.resize(x.len() + shift, 0);
+    }
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < x.len() || carry > 0 {
+        if shift + i == acc.len() {
+            acc.push(0);
+        }
+        let sum = acc[shift + i] as u64 + x.get(i).copied().unwrap_or(0) as u64 + carry;
+        acc[shift + i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+
+    // a = a1 B^m + a0, b = b1 B^m + b0,
+    // ab = z2 B^2m + ((a0 + a1)(b0 + b1) - z2 - z0) B^m + z0
+    let m = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let (b0, b1) = b.split_at(m.min(b.len()));
+    let a0 = trim(a0);
+    let b0 = trim(b0);
+
+    let z0 = mul_mag(a0, b0);
+    let z2 = mul_mag(a1, b1);
+    let z1 = mul_mag(&add_mag(a0, a1), &add_mag(b0, b1));
+    let z1 = sub_mag(&sub_mag(&z1, &z0), &z2);
+
+    let mut result = z0;
+    add_shifted(&mut result, &z1, m);
+    add_shifted(&mut result, &z2, 2 * m);
+    while result.last() == Some(&0) {
+        result.pop();
+    }
+    result
+}
+
+fn trim(x: &[u32]) -> &[u32] {
+    let len = x.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1);
+    &x[..len]
+}
+
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    while result.last() == Some(&0) {
+        result.pop();
+    }
+    result
+}
+
+/// `mag = mag * factor + addend`.
+fn mul_add_small(mag: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = addend as u64;
+    for limb in mag.iter_mut() {
+        let value = *limb as u64 * factor as u64 + carry;
+        *limb = value as u32;
+        carry = value >> 32;
+    }
+    if carry > 0 {
+        mag.push(carry as u32);
+    }
+}
+
+/// Divide in place and return the remainder.
+fn div_rem_small(mag: &mut Vec<u32>, divisor: u32) -> u32 {
+    let mut rem = 0u64;
+    for limb in mag.iter_mut().rev() {
+        let value = (rem << 32) | *limb as u64;
+        *limb = (value / divisor as u64) as u32;
+        rem = value % divisor as u64;
+    }
+    while mag.last() == Some(&0) {
+        mag.pop();
+    }
+    rem as u32
+}
+
+/// Quotient and remainder of magnitudes by Knuth's algorithm D, in the form of
+/// Warren, "Hacker's Delight", `divmnu`.
+fn div_rem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_mag(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let mut q = a.to_vec();
+        let r = div_rem_small(&mut q, b[0]);
+        return (q, if r == 0 { Vec::new() } else { vec![r] });
+    }
+
+    // normalize so the leading divisor limb has its top bit set
+    let n = b.len();
+    let shift = b[n - 1].leading_zeros();
+    let vn = shl_bits(b, shift);
+    let mut un = shl_bits(a, shift);
+    un.resize(a.len() + 1, 0);
+
+    let base = 1u64 << 32;
+    let mut q = vec![0u32; a.len() - n + 1];
+    for j in (0..=a.len() - n).rev() {
+        let num = ((un[j + n] as u64) << 32) | un[j + n - 1] as u64;
+        let mut qhat = num / vn[n - 1] as u64;
+        let mut rhat = num % vn[n - 1] as u64;
+        while qhat >= base || qhat * vn[n - 2] as u64 > ((rhat << 32) | un[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += vn[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        // multiply and subtract
+        let mut k = 0i64;
+        for i in 0..n {
+            let p = qhat * vn[i] as u64;
+            let t = un[i + j] as i64 - k - (p & 0xFFFF_FFFF) as i64;
+            un[i + j] = t as u32;
+            k = (p >> 32) as i64 - (t >> 32);
+        }
+        let t = un[j + n] as i64 - k;
+        un[j + n] = t as u32;
+
+        // qhat was one too large, add back
+        if t < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = un[i + j] as u64 + vn[i] as u64 + carry;
+                un[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            un[j + n] = un[j + n].wrapping_add(carry as u32);
+        }
+        q[j] = qhat as u32;
+    }
+
+    let mut r = shr_bits(&un[..n], shift);
+    while q.last() == Some(&0) {
+        q.pop();
+    }
+    while r.last() == Some(&0) {
+        r.pop();
+    }
+    (q, r)
+}
+
+fn shl_bits(x: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return x.to_vec();
+    }
+    let mut result = Vec::with_capacity(x.len() + 1);
+    let mut carry = 0u32;
+    for &limb in x {
+        result.push((limb << shift) | carry);
+        carry = limb >> (32 - shift);
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+    result
+}
+
+fn shr_bits(x: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return x.to_vec();
+    }
+    (0..x.len())
+        .map(|i| (x[i] >> shift) | x.get(i + 1).map_or(0, |&next| next << (32 - shift)))
+        .collect()
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    let mut mag = (value as i128).unsigned_abs();
+                    let mut limbs = Vec::new();
+                    while mag > 0 {
+                        limbs.push(mag as u32);
+                        mag >>= 32;
+                    }
+                    Self::from_parts(value < 0, limbs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    let mut mag = value as u128;
+                    let mut limbs = Vec::new();
+                    while mag > 0 {
+                        limbs.push(mag as u32);
+                        mag >>= 32;
+                    }
+                    Self::from_parts(false, limbs)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl FromStr for BigInt {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BigInt({})", self.to_str_radix(10))
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_mag(&self.mag, &other.mag),
+            (true, true) => cmp_mag(&other.mag, &self.mag),
+        }
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(self.negative, add_mag(&self.mag, &rhs.mag));
+        }
+        match cmp_mag(&self.mag, &rhs.mag) {
+            Ordering::Less => BigInt::from_parts(rhs.negative, sub_mag(&rhs.mag, &self.mag)),
+            _ => BigInt::from_parts(self.negative, sub_mag(&self.mag, &rhs.mag)),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(self.negative != rhs.negative, mul_mag(&self.mag, &rhs.mag))
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.mag.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.mag)
+    }
+}
+
+macro_rules! forward_binop {
+    ($($op:ident, $method:ident, $assign:ident, $assign_method:ident);*) => {
+        $(
+            impl $op for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $op<&BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: &BigInt) -> BigInt {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $op<BigInt> for &BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> BigInt {
+                    self.$method(&rhs)
+                }
+            }
+
+            impl $assign for BigInt {
+                fn $assign_method(&mut self, rhs: BigInt) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+
+            impl $assign<&BigInt> for BigInt {
+                fn $assign_method(&mut self, rhs: &BigInt) {
+                    *self = (&*self).$method(rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward_binop!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign
+);
+
+impl Zero for BigInt {
+    #[inline]
+    fn zero() -> Self {
+        Self { negative: false, mag: Vec::new() }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.mag.is_empty()
+    }
+}
+
+impl One for BigInt {
+    #[inline]
+    fn one() -> Self {
+        Self { negative: false, mag: vec![1] }
+    }
+}
+
+impl Ring for BigInt {}
+
+impl Integer for BigInt {
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    #[inline]
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(self - other)
+    }
+
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn checked_gcd(&self, other: &Self) -> Option<Self> {
+        Some(BigInt::gcd(self, other))
+    }
+}
@@ -0,0 +1,212 @@
+use std::ops::Mul;
+use std::fmt;
+
+/// Matrix over GF(2) with runtime shape, each row packed into `u64` words.
+///
+/// Row operations work a word at a time, addition of rows is XOR, which
+/// makes elimination on large binary matrices about 64 times cheaper than
+/// on a `DMat<Fp<2>>`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BitMat {
+    rows: usize,
+    cols: usize,
+    words: usize,
+    data: Vec<u64>,
+}
+
+impl BitMat {
+    /// All-zero matrix.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let words = cols.div_ceil(64);
+        Self { rows, cols, words, data: vec![0; rows * words] }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut mat = Self::new(n, n);
+        for i in 0..n {
+            mat.set(i, i, true);
+        }
+        mat
+    }
+
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> bool,
+    {
+        let mut mat = Self::new(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                if f(i, j) {
+                    mat.set(i, j, true);
+                }
+            }
+        }
+        mat
+    }
+
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Panics if the index is out of bounds.
+    #[inline]
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        assert!(row < self.rows && col < self.cols, "Index ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols);
+        self.data[row * self.words + col / 64] >> (col % 64) & 1 == 1
+    }
+
+    /// Panics if the index is out of bounds.
+    #[inline]
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        assert!(row < self.rows && col < self.cols, "Index ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols);
+        let word = &mut self.data[row * self.words + col / 64];
+        if value {
+            *word |= 1 << (col % 64);
+        } else {
+            *word &= !(1 << (col % 64));
+        }
+    }
+
+    /// Packed words of a row, column `j` is bit `j % 64` of word `j / 64` and
+    /// the unused high bits of the last word are zero.
+    #[inline]
+    pub fn row_words(&self, row: usize) -> &[u64] {
+        &self.data[row * self.words..(row + 1) * self.words]
+    }
+
+    /// Adds row `src` to row `dst`, i.e. `dst ^= src`.
+    pub fn xor_row(&mut self, dst: usize, src: usize) {
+        assert!(dst < self.rows && src < self.rows, "Row index out of range");
+        for w in 0..self.words {
+            let word = self.data[src * self.words + w];
+            self.data[dst * self.words + w] ^= word;
+        }
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows && b < self.rows, "Row index out of range");
+        for w in 0..self.words {
+            self.data.swap(a * self.words + w, b * self.words + w);
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |i, j| self.get(j, i))
+    }
+
+    /// Reduced row echelon form and the pivot columns.
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        let mut a = self.clone();
+        let pivots = a.eliminate(None);
+        (a, pivots)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// Basis of the solutions of `A x = 0` as the rows of a matrix with
+    /// `cols` columns, one row per free column.
+    pub fn nullspace(&self) -> Self {
+        let (a, pivots) = self.rref();
+        let free: Vec<usize> = (0..self.cols).filter(|col| !pivots.contains(col)).collect();
+        let mut basis = Self::new(free.len(), self.cols);
+        for (k, &f) in free.iter().enumerate() {
+            basis.set(k, f, true);
+            for (row, &col) in pivots.iter().enumerate() {
+                if a.get(row, f) {
+                    basis.set(k, col, true);
+                }
+            }
+        }
+        basis
+    }
+
+    /// `None` if the matrix is singular, panics if it isn't square.
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.rows, self.cols, "Matrix must be square");
+        let mut a = self.clone();
+        let mut inv = Self::identity(self.rows);
+        let pivots = a.eliminate(Some(&mut inv));
+        (pivots.len() == self.rows).then_some(inv)
+    }
+
+    /// Gauss–Jordan elimination in place, repeating every row operation on
+    /// `other` when given. Returns the pivot columns.
+    fn eliminate(&mut self, mut other: Option<&mut Self>) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for col in 0..self.cols {
+            let row = pivots.len();
+            if row == self.rows {
+                break;
+            }
+            let Some(pivot) = (row..self.rows).find(|&i| self.get(i, col)) else {
+                continue;
+            };
+            self.swap_rows(row, pivot);
+            if let Some(other) = other.as_deref_mut() {
+                other.swap_rows(row, pivot);
+            }
+            for i in 0..self.rows {
+                if i != row && self.get(i, col) {
+                    self.xor_row(i, row);
+                    if let Some(other) = other.as_deref_mut() {
+                        other.xor_row(i, row);
+                    }
+                }
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+}
+
+impl Mul for &BitMat {
+    type Output = BitMat;
+
+    /// Row `i` of the product is the XOR of the rows of `rhs` selected by the
+    /// set bits of row `i` of `self`. Panics if the inner dimensions differ.
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows, "Matrix dimensions do not match");
+        let mut product = BitMat::new(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in (0..self.cols).filter(|&k| self.get(i, k)) {
+                for w in 0..rhs.words {
+                    product.data[i * rhs.words + w] ^= rhs.data[k * rhs.words + w];
+                }
+            }
+        }
+        product
+    }
+}
+
+impl Mul for BitMat {
+    type Output = BitMat;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl fmt::Debug for BitMat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                write!(f, "{}", if self.get(i, j) { '1' } else { '0' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
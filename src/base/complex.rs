@@ -0,0 +1,361 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+use std::fmt;
+
+use crate::base::matrix::Mat;
+use crate::base::num::{Field, One, Real, Ring, Zero};
+
+/// Complex number `re + im i`.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    #[inline]
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Ring + Copy,
+{
+    /// The imaginary unit.
+    #[inline]
+    pub fn i() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+
+    /// `re² + im²`, the squared modulus.
+    #[inline]
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Field + Copy,
+{
+    #[inline]
+    pub fn conj(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Real,
+{
+    /// `r (cos θ + i sin θ)`.
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// Modulus and argument `(r, θ)`.
+    pub fn to_polar(&self) -> (T, T) {
+        (self.abs(), self.arg())
+    }
+
+    /// Modulus, scaled so that it does not overflow for large components.
+    pub fn abs(&self) -> T {
+        let scale = self.re.abs().max(self.im.abs());
+        if scale == T::zero() || !scale.is_finite() {
+            return scale;
+        }
+        let (re, im) = (self.re / scale, self.im / scale);
+        scale * (re * re + im * im).sqrt()
+    }
+
+    /// Argument in `(-π, π]`.
+    pub fn arg(&self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Principal logarithm, the imaginary part is the argument.
+    pub fn ln(&self) -> Self {
+        Self::new(self.abs().ln(), self.arg())
+    }
+
+    /// Principal square root, with a non-negative real part.
+    pub fn sqrt(&self) -> Self {
+        let zero = T::zero();
+        if self.re == zero && self.im == zero {
+            return *self;
+        }
+        // avoid cancellation by computing the larger component first
+        let half = T::from_f64(0.5);
+        let t = ((self.re.abs() + self.abs()) * half).sqrt();
+        if self.re >= zero {
+            Self::new(t, self.im / (t + t))
+        } else {
+            let im = if self.im < zero { -t } else { t };
+            Self::new(self.im.abs() / (t + t), im)
+        }
+    }
+
+    /// Principal power `exp(w ln z)`, `0^w = 0` for `w ≠ 0`.
+    pub fn powc(&self, w: Self) -> Self {
+        if self.is_zero() {
+            return if w.is_zero() { Self::one() } else { Self::zero() };
+        }
+        (w * self.ln()).exp()
+    }
+
+    /// Principal power with a real exponent.
+    pub fn powf(&self, n: T) -> Self {
+        if self.is_zero() {
+            return if n == T::zero() { Self::one() } else { Self::zero() };
+        }
+        let (r, theta) = self.to_polar();
+        Self::from_polar(r.powf(n), theta * n)
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+}
+
+impl<T> From<T> for Complex<T>
+where
+    T: Zero,
+{
+    fn from(re: T) -> Self {
+        Self::new(re, T::zero())
+    }
+}
+
+impl<T> Add for Complex<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T> Sub for Complex<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Ring + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: Field + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let (re, im) = T::complex_div(self.re, self.im, rhs.re, rhs.im);
+        Self::new(re, im)
+    }
+}
+
+/// `(a + b i) / (c + d i)` with the divisor scaled by the power of two `s`
+/// at or below the larger of `|c|` and `|d|`, so that `(c/s)² + (d/s)²` lies
+/// in `[1, 4)`. The scaling is exact and the result equals the textbook
+/// formula wherever that does not overflow or underflow.
+pub(crate) fn scaled_div<T>(a: T, b: T, c: T, d: T) -> (T, T)
+where
+    T: Real,
+{
+    let largest = c.abs().max(d.abs()).to_f64();
+    // clamped so that both 2^e and 2^-e are finite, zero and non-finite
+    // divisors are left unscaled
+    let exponent = if largest.is_finite() && largest > 0.0 { (largest.log2().floor() as i32).clamp(-1022, 1023) } else { 0 };
+    let inverse = T::from_f64(2f64.powi(-exponent));
+    let (c, d) = (c * inverse, d * inverse);
+    let denominator = c * c + d * d;
+    let re = (a * c + b * d) / denominator;
+    let im = (b * c - a * d) / denominator;
+    (re * inverse, im * inverse)
+}
+
+impl<T> Neg for Complex<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T> Mul<T> for Complex<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl<T> Div<T> for Complex<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+impl<T> AddAssign for Complex<T>
+where
+    T: Add<Output = T> + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> SubAssign for Complex<T>
+where
+    T: Sub<Output = T> + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> MulAssign for Complex<T>
+where
+    T: Ring + Copy,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> DivAssign for Complex<T>
+where
+    T: Field + Copy,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+macro_rules! impl_scalar_complex {
+    ($($t:ty),*) => {
+        $(
+            impl Mul<Complex<$t>> for $t {
+                type Output = Complex<$t>;
+
+                fn mul(self, rhs: Complex<$t>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl Add<Complex<$t>> for $t {
+                type Output = Complex<$t>;
+
+                fn add(self, rhs: Complex<$t>) -> Self::Output {
+                    Complex::new(self + rhs.re, rhs.im)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_complex!(f32, f64);
+
+impl<T> Zero for Complex<T>
+where
+    T: Zero,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T> One for Complex<T>
+where
+    T: Ring + Copy,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Ring for Complex<T> where T: Ring + Copy {}
+
+impl<T> Field for Complex<T> where T: Field + Copy {}
+
+impl<T> fmt::Display for Complex<T>
+where
+    T: fmt::Display + PartialOrd + Zero + Neg<Output = T> + Copy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.im < T::zero() {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<Complex<T>, R, C>
+where
+    T: Real,
+{
+    /// The adjoint `Aᴴ`, the transpose with every element conjugated.
+    pub fn conjugate_transpose(&self) -> Mat<Complex<T>, C, R> {
+        Mat::from_fn(|i, j| self.data[j][i].conj())
+    }
+
+    /// `AᴴA = I` within `tolerance` for the modulus of every element.
+    pub fn is_unitary(&self, tolerance: T) -> bool {
+        let gram = self.conjugate_transpose() * *self;
+        (gram - Mat::<Complex<T>, C, C>::identity()).iter().all(|x| x.abs() <= tolerance)
+    }
+}
+
+impl<T, const N: usize> Mat<Complex<T>, N, N>
+where
+    T: Real,
+{
+    /// `A = Aᴴ` within `tolerance` for the modulus of every element.
+    pub fn is_hermitian(&self, tolerance: T) -> bool {
+        (0..N).all(|i| (0..=i).all(|j| (self.data[i][j] - self.data[j][i].conj()).abs() <= tolerance))
+    }
+}
@@ -0,0 +1,437 @@
+use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
+use std::fmt;
+
+use crate::base::matrix::Mat;
+use crate::base::num::{One, Zero};
+
+/// Matrix with runtime shape, stored in row-major order.
+///
+/// It is the fallback for results whose shape can't be expressed with
+/// stable const generics, e.g. the concatenation of two `Mat`.
+pub struct DMat<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> DMat<T> {
+
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, &'static str> {
+        if data.len() != rows * cols {
+            return Err("Vector length does not match matrix dimensions");
+        }
+        Ok(Self { rows, cols, data })
+    }
+
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Self { rows, cols, data }
+    }
+
+    #[inline]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get(row * self.cols + col)
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get_mut(row * self.cols + col)
+    }
+
+    /// Elements in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.cols.max(1)).take(self.rows)
+    }
+
+    pub fn map<U, F>(self, f: F) -> DMat<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        DMat {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> DMat<T>
+where
+    T: Clone,
+{
+    pub fn from_elem(rows: usize, cols: usize, value: T) -> Self {
+        Self { rows, cols, data: vec![value; rows * cols] }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone())
+    }
+
+    pub fn submatrix(&self, row: usize, col: usize, rows: usize, cols: usize) -> Result<Self, &'static str> {
+        if row + rows > self.rows || col + cols > self.cols {
+            return Err("Index out of bounds");
+        }
+        Ok(Self::from_fn(rows, cols, |i, j| self[(row + i, col + j)].clone()))
+    }
+
+    /// Overwrite the block starting at `(row, col)` with `block`.
+    pub fn set_submatrix(&mut self, row: usize, col: usize, block: &DMat<T>) -> Result<(), &'static str> {
+        if row + block.rows > self.rows || col + block.cols > self.cols {
+            return Err("Index out of bounds");
+        }
+        for i in 0..block.rows {
+            for j in 0..block.cols {
+                self[(row + i, col + j)] = block[(i, j)].clone();
+            }
+        }
+        Ok(())
+    }
+
+    /// `[self, other]`, both matrices must have the same number of rows.
+    pub fn hstack(&self, other: &Self) -> Result<Self, &'static str> {
+        if self.rows != other.rows {
+            return Err("Row counts do not match");
+        }
+        Ok(Self::from_fn(self.rows, self.cols + other.cols, |i, j| {
+            if j < self.cols {
+                self[(i, j)].clone()
+            } else {
+                other[(i, j - self.cols)].clone()
+            }
+        }))
+    }
+
+    /// `[self; other]`, both matrices must have the same number of columns.
+    pub fn vstack(&self, other: &Self) -> Result<Self, &'static str> {
+        if self.cols != other.cols {
+            return Err("Column counts do not match");
+        }
+        let mut data = self.data.clone();
+        data.extend_from_slice(&other.data);
+        Ok(Self { rows: self.rows + other.rows, cols: self.cols, data })
+    }
+
+    /// Assemble a matrix from a grid of blocks, e.g.
+    /// `DMat::from_blocks(&[&[&a, &b], &[&c, &d]])`.
+    ///
+    /// Blocks in a block row must have the same number of rows and blocks in a
+    /// block column the same number of columns.
+    pub fn from_blocks(blocks: &[&[&DMat<T>]]) -> Result<Self, &'static str> {
+        let Some(first_row) = blocks.first() else {
+            return Err("No blocks");
+        };
+
+        let block_cols: Vec<usize> = first_row.iter().map(|block| block.cols).collect();
+        let cols = block_cols.iter().sum();
+        let rows = blocks.iter().map(|row| row.first().map_or(0, |block| block.rows)).sum();
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for block_row in blocks {
+            if block_row.len() != block_cols.len() {
+                return Err("Block rows have different lengths");
+            }
+            let Some(first) = block_row.first() else {
+                return Err("Empty block row");
+            };
+            let height = first.rows;
+            for (block, &width) in block_row.iter().zip(&block_cols) {
+                if block.rows != height || block.cols != width {
+                    return Err("Block dimensions do not match");
+                }
+            }
+            for i in 0..height {
+                for block in block_row.iter() {
+                    data.extend_from_slice(&block.data[i * block.cols..(i + 1) * block.cols]);
+                }
+            }
+        }
+
+        Ok(Self { rows, cols, data })
+    }
+
+    /// Stack the columns into one `(rows * cols) x 1` matrix.
+    pub fn vec(&self) -> Self {
+        Self::from_fn(self.rows * self.cols, 1, |k, _| self[(k % self.rows, k / self.rows)].clone())
+    }
+
+    /// Inverse of `vec`, fill the columns of a `rows x cols` matrix from `v`.
+    pub fn unvec(v: &[T], rows: usize, cols: usize) -> Result<Self, &'static str> {
+        if v.len() != rows * cols {
+            return Err("Vector length does not match matrix dimensions");
+        }
+        Ok(Self::from_fn(rows, cols, |i, j| v[j * rows + i].clone()))
+    }
+
+    /// Convert to a fixed-size matrix, fails if the shape is not `R x C`.
+    pub fn to_mat<const R: usize, const C: usize>(&self) -> Result<Mat<T, R, C>, &'static str> {
+        if self.rows != R || self.cols != C {
+            return Err("Matrix dimensions do not match");
+        }
+        Ok(Mat::new(std::array::from_fn(|i| std::array::from_fn(|j| self[(i, j)].clone()))))
+    }
+}
+
+impl<T> DMat<T>
+where
+    T: Zero + One + Clone,
+{
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::from_elem(rows, cols, T::zero())
+    }
+
+    pub fn ones(rows: usize, cols: usize) -> Self {
+        Self::from_elem(rows, cols, T::one())
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+
+    /// The commutation matrix `K` with `K * vec(A) = vec(Aᵀ)` for an `m x n` matrix `A`.
+    pub fn commutation(m: usize, n: usize) -> Self {
+        Self::from_fn(m * n, m * n, |row, col| if row == (col % m) * n + col / m { T::one() } else { T::zero() })
+    }
+
+    /// The KKT matrix `[[h, aᵀ], [a, 0]]` of an equality constrained quadratic program.
+    pub fn kkt(h: &Self, a: &Self) -> Result<Self, &'static str> {
+        if !h.is_square() || a.cols != h.rows {
+            return Err("Matrix dimensions do not match");
+        }
+        let zero = Self::zeros(a.rows, a.rows);
+        Self::from_blocks(&[&[h, &a.transpose()], &[a, &zero]])
+    }
+}
+
+impl<T> DMat<T>
+where
+    T: Mul<Output = T> + Clone,
+{
+    /// Kronecker product `self ⊗ other`.
+    pub fn kronecker(&self, other: &Self) -> Self {
+        Self::from_fn(self.rows * other.rows, self.cols * other.cols, |i, j| {
+            self[(i / other.rows, j / other.cols)].clone() * other[(i % other.rows, j % other.cols)].clone()
+        })
+    }
+
+    /// Outer product `a * bᵀ`.
+    pub fn outer(a: &[T], b: &[T]) -> Self {
+        Self::from_fn(a.len(), b.len(), |i, j| a[i].clone() * b[j].clone())
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<Mat<T, R, C>> for DMat<T> {
+    fn from(mat: Mat<T, R, C>) -> Self {
+        Self {
+            rows: R,
+            cols: C,
+            data: mat.data.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMat<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols, "Index ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols);
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMat<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols, "Index ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols);
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<T> Add for DMat<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    /// Panics if the shapes differ.
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.shape(), rhs.shape(), "Matrix dimensions do not match");
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().zip(rhs.data).map(|(a, b)| a + b).collect(),
+        }
+    }
+}
+
+impl<T> Sub for DMat<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    /// Panics if the shapes differ.
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.shape(), rhs.shape(), "Matrix dimensions do not match");
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().zip(rhs.data).map(|(a, b)| a - b).collect(),
+        }
+    }
+}
+
+impl<T> Neg for DMat<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<T> Mul<T> for DMat<T>
+where
+    T: Mul<Output = T> + Clone,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        self.map(|x| x * scalar.clone())
+    }
+}
+
+impl<T> Div<T> for DMat<T>
+where
+    T: Div<Output = T> + Clone,
+{
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        self.map(|x| x / scalar.clone())
+    }
+}
+
+impl<T> Mul for &DMat<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Clone,
+{
+    type Output = DMat<T>;
+
+    /// Panics if the inner dimensions differ.
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.cols, rhs.rows, "Matrix dimensions do not match");
+        DMat::from_fn(self.rows, rhs.cols, |i, j| {
+            let mut sum = T::zero();
+            for k in 0..self.cols {
+                sum = sum + self[(i, k)].clone() * rhs[(k, j)].clone();
+            }
+            sum
+        })
+    }
+}
+
+impl<T> Mul for DMat<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Clone,
+{
+    type Output = DMat<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<T> PartialEq for DMat<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.shape() == other.shape() && self.data == other.data
+    }
+}
+
+impl<T> Clone for DMat<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for DMat<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Matrix {}x{}:", self.rows, self.cols)?;
+        for row in self.rows_iter() {
+            write!(f, "[")?;
+            for (j, x) in row.iter().enumerate() {
+                write!(f, "{:?}", x)?;
+                if j + 1 < self.cols {
+                    write!(f, ", ")?;
+                }
+            }
+            writeln!(f, "]")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+use macro_utils::define_math_family;
+
+define_math_family!(i32);
@@ -1,6 +1,10 @@
-use std::ops::{Add, Sub, Mul, Div, Index, IndexMut};
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, Index, IndexMut};
+use std::cmp::Ordering;
 use std::fmt;
 
+use crate::base::num::{One, Zero};
+use crate::base::vector::Vector;
+
 pub struct Mat<T, const R: usize, const C: usize> {
     pub data: [[T; C]; R],
 }
@@ -52,109 +56,220 @@ impl<T, const R: usize, const C: usize> Mat<T, R, C> {
 
 impl<T, const R: usize, const C: usize> Add for Mat<T, R, C>
 where
-    T: Add<Output = T> + Copy,
+    T: Add<Output = T>,
 {
     type Output = Self;
     
     fn add(self, rhs: Self) -> Self::Output {
-        let mut result = self;
-        for i in 0..R {
-            for j in 0..C {
-                result.data[i][j] = result.data[i][j] + rhs.data[i][j];
-            }
-        }
-        result
+        self.zip_map(rhs, |a, b| a + b)
     }
 }
 
 impl<T, const R: usize, const C: usize> Sub for Mat<T, R, C>
 where
-    T: Sub<Output = T> + Copy,
+    T: Sub<Output = T>,
 {
     type Output = Self;
     
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut result = self;
-        for i in 0..R {
-            for j in 0..C {
-                result.data[i][j] = result.data[i][j] - rhs.data[i][j];
-            }
-        }
-        result
+        self.zip_map(rhs, |a, b| a - b)
     }
 }
 
 impl<T, const R: usize, const C: usize> Mul<T> for Mat<T, R, C>
 where
-    T: Mul<Output = T> + Copy,
+    T: Mul<Output = T> + Clone,
 {
     type Output = Self;
     
     fn mul(self, scalar: T) -> Self::Output {
-        let mut result = self;
-        for i in 0..R {
-            for j in 0..C {
-                result.data[i][j] = result.data[i][j] * scalar;
-            }
-        }
-        result
+        self.map(|x| x * scalar.clone())
     }
 }
 
 impl<T, const R: usize, const C: usize> Div<T> for Mat<T, R, C>
 where
-    T: Div<Output = T> + Copy,
+    T: Div<Output = T> + Clone,
 {
     type Output = Self;
     
     fn div(self, scalar: T) -> Self::Output {
-        let mut result = self;
-        for i in 0..R {
-            for j in 0..C {
-                result.data[i][j] = result.data[i][j] / scalar;
-            }
+        self.map(|x| x / scalar.clone())
+    }
+}
+
+impl<T, const R: usize, const C: usize> AddAssign for Mat<T, R, C>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.iter_mut().zip(rhs.data.into_iter().flatten()) {
+            *a += b;
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> SubAssign for Mat<T, R, C>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.iter_mut().zip(rhs.data.into_iter().flatten()) {
+            *a -= b;
         }
-        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> MulAssign<T> for Mat<T, R, C>
+where
+    T: MulAssign + Clone,
+{
+    fn mul_assign(&mut self, scalar: T) {
+        for a in self.iter_mut() {
+            *a *= scalar.clone();
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> DivAssign<T> for Mat<T, R, C>
+where
+    T: DivAssign + Clone,
+{
+    fn div_assign(&mut self, scalar: T) {
+        for a in self.iter_mut() {
+            *a /= scalar.clone();
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Neg for Mat<T, R, C>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
     }
 }
 
 impl<T, const R: usize, const C: usize, const C2: usize> Mul<Mat<T, C, C2>> for Mat<T, R, C>
 where
-    T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    T: Mul<Output = T> + Add<Output = T> + Zero + Clone,
 {
     type Output = Mat<T, R, C2>;
     
     fn mul(self, rhs: Mat<T, C, C2>) -> Self::Output {
-        let mut result_data = [[T::default(); C2]; R];
-        
-        for i in 0..R {
-            for j in 0..C2 {
-                let mut sum = T::default();
-                for k in 0..C {
-                    sum = sum + (self.data[i][k] * rhs.data[k][j]);
-                }
-                result_data[i][j] = sum;
-            }
-        }
-        
-        Mat { data: result_data }
+        Mat::from_fn(|i, j| {
+            (0..C).fold(T::zero(), |sum, k| sum + self.data[i][k].clone() * rhs.data[k][j].clone())
+        })
     }
 }
 
 impl<T, const R: usize, const C: usize> Mat<T, R, C>
 where
-    T: Copy,
+    T: Clone,
 {
     pub fn transpose(&self) -> Mat<T, C, R> {
-        let mut result_data = [[self.data[0][0]; R]; C];
-        
-        for i in 0..R {
-            for j in 0..C {
-                result_data[j][i] = self.data[i][j];
-            }
+        Mat::from_fn(|i, j| self.data[j][i].clone())
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C> {
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self {
+            data: std::array::from_fn(|i| std::array::from_fn(|j| f(i, j))),
         }
-        
-        Mat { data: result_data }
+    }
+
+    pub fn map<U, F>(self, mut f: F) -> Mat<U, R, C>
+    where
+        F: FnMut(T) -> U,
+    {
+        Mat {
+            data: self.data.map(|row| row.map(&mut f)),
+        }
+    }
+
+    pub fn zip_map<U, V, F>(self, other: Mat<U, R, C>, mut f: F) -> Mat<V, R, C>
+    where
+        F: FnMut(T, U) -> V,
+    {
+        let mut rhs = other.data.into_iter();
+        Mat {
+            data: self.data.map(|row| {
+                let mut rhs_row = rhs.next().unwrap().into_iter();
+                row.map(|x| f(x, rhs_row.next().unwrap()))
+            }),
+        }
+    }
+
+    /// Fold every element in row-major order.
+    pub fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, T) -> B,
+    {
+        self.data.into_iter().flatten().fold(init, f)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Copy,
+{
+    /// Element-wise product.
+    pub fn hadamard(&self, other: &Self) -> Self
+    where
+        T: Mul<Output = T>,
+    {
+        self.zip_map(*other, |a, b| a * b)
+    }
+
+    /// Element-wise division.
+    pub fn component_div(&self, other: &Self) -> Self
+    where
+        T: Div<Output = T>,
+    {
+        self.zip_map(*other, |a, b| a / b)
+    }
+
+    /// Element-wise minimum.
+    pub fn min(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.zip_map(*other, |a, b| if b < a { b } else { a })
+    }
+
+    /// Element-wise maximum.
+    pub fn max(&self, other: &Self) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.zip_map(*other, |a, b| if b > a { b } else { a })
+    }
+
+    pub fn abs(&self) -> Self
+    where
+        T: PartialOrd + Neg<Output = T> + Zero,
+    {
+        self.map(|x| if x < T::zero() { -x } else { x })
+    }
+
+    /// Largest absolute value of all elements, zero for an empty matrix and
+    /// NaN if any element is NaN.
+    pub fn max_abs_element(&self) -> T
+    where
+        T: PartialOrd + Neg<Output = T> + Zero,
+    {
+        // a NaN x is unordered and replaces max, a NaN max is never replaced
+        self.abs().fold(T::zero(), |max, x| match x.partial_cmp(&max) {
+            Some(Ordering::Greater) | None if max >= T::zero() => x,
+            _ => max,
+        })
     }
 }
 
@@ -194,47 +309,146 @@ where
 
 impl<T, const N: usize> Mat<T, N, N>
 where
-    T: Default + Copy,
+    T: Zero,
 {
     pub fn identity() -> Self 
     where
-        T: From<u8> + PartialEq + Default + Copy,
+        T: One,
     {
-        let mut result = Self::default();
-        for i in 0..N {
-            result.data[i][i] = T::from(1u8);
-        }
-        result
+        Self::from_fn(|i, j| if i == j { T::one() } else { T::zero() })
     }
     
-    pub fn diag(diagonal: [T; N]) -> Self 
+    pub fn diag(diagonal: [T; N]) -> Self
     where
-        T: Default + Copy,
+        T: Clone,
     {
-        let mut result = Self::default();
-        for i in 0..N {
-            result.data[i][i] = diagonal[i];
+        Self::from_fn(|i, j| if i == j { diagonal[i].clone() } else { T::zero() })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Copy,
+{
+    /// Build a matrix from its columns, e.g. `Mat3::from_cols([tangent, bitangent, normal])`.
+    pub fn from_cols<V>(cols: [V; C]) -> Self
+    where
+        V: Into<[T; R]>,
+    {
+        let cols: [[T; R]; C] = cols.map(Into::into);
+        Self::from_fn(|i, j| cols[j][i])
+    }
+
+    pub fn from_rows<V>(rows: [V; R]) -> Self
+    where
+        V: Into<[T; C]>,
+    {
+        Self { data: rows.map(Into::into) }
+    }
+
+    /// The `i`-th column, convert it into a named vector with `.into()`.
+    pub fn col(&self, i: usize) -> Vector<T, R> {
+        Vector::from_fn(|j| self.data[j][i])
+    }
+
+    pub fn row(&self, i: usize) -> Vector<T, C> {
+        Vector::new(self.data[i])
+    }
+
+    pub fn set_col<V>(&mut self, i: usize, col: V)
+    where
+        V: Into<[T; R]>,
+    {
+        let col: [T; R] = col.into();
+        for (row, value) in self.data.iter_mut().zip(col) {
+            row[i] = value;
         }
-        result
+    }
+
+    pub fn set_row<V>(&mut self, i: usize, row: V)
+    where
+        V: Into<[T; C]>,
+    {
+        self.data[i] = row.into();
+    }
+
+    pub fn cols_iter(&self) -> impl Iterator<Item = [T; R]> + '_ {
+        (0..C).map(|j| std::array::from_fn(|i| self.data[i][j]))
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Clone,
+{
+    pub fn diagonal(&self) -> [T; N] {
+        std::array::from_fn(|i| self.data[i][i].clone())
+    }
+
+    pub fn trace(&self) -> T
+    where
+        T: Add<Output = T> + Zero,
+    {
+        self.diagonal().into_iter().fold(T::zero(), |sum, x| sum + x)
     }
 }
 
 impl<T, const R: usize, const C: usize> Mat<T, R, C>
 where
-    T: Copy + Default,
+    T: Copy + Zero,
 {
+    /// The `R1 x C1` block starting at row `I`, column `J`, a block that does not
+    /// fit in the matrix fails to compile.
     pub fn submatrix<const I: usize, const J: usize, const R1: usize, const C1: usize>(&self) -> Mat<T, R1, C1> {
-        let mut result = Mat::default();
+        const { assert!(I + R1 <= R && J + C1 <= C, "submatrix out of bounds") };
+        Mat::from_fn(|i, j| self.data[I + i][J + j])
+    }
+
+    /// The block starting at `(row, col)`, its size is inferred from the result type,
+    /// e.g. `let m3: Mat3 = m4.block(0, 0)`.
+    ///
+    /// Panics if the block does not fit in the matrix.
+    pub fn block<const R1: usize, const C1: usize>(&self, row: usize, col: usize) -> Mat<T, R1, C1> {
+        assert!(row + R1 <= R && col + C1 <= C, "block {}x{} at ({}, {}) out of bounds for {}x{} matrix", R1, C1, row, col, R, C);
+        Mat::from_fn(|i, j| self.data[row + i][col + j])
+    }
+    
+    /// Overwrite the block starting at row `I`, column `J` with `block`, a block
+    /// that does not fit in the matrix fails to compile.
+    pub fn set_submatrix<const I: usize, const J: usize, const R1: usize, const C1: usize>(&mut self, block: &Mat<T, R1, C1>) {
+        const { assert!(I + R1 <= R && J + C1 <= C, "submatrix out of bounds") };
         for i in 0..R1 {
-            for j in 0..C1 {
-                if I + i < R && J + j < C {
-                    result.data[i][j] = self.data[I + i][J + j];
-                }
-            }
+            self.data[I + i][J..J + C1].copy_from_slice(&block.data[i]);
         }
-        result
     }
-    
+
+    /// Overwrite the block starting at `(row, col)` with `block`.
+    ///
+    /// Panics if the block does not fit in the matrix.
+    pub fn set_block<const R1: usize, const C1: usize>(&mut self, row: usize, col: usize, block: &Mat<T, R1, C1>) {
+        assert!(row + R1 <= R && col + C1 <= C, "block {}x{} at ({}, {}) out of bounds for {}x{} matrix", R1, C1, row, col, R, C);
+        for i in 0..R1 {
+            self.data[row + i][col..col + C1].copy_from_slice(&block.data[i]);
+        }
+    }
+
+    /// `[self, other]`, the result width is inferred and must equal `C + C2`,
+    /// e.g. `let augmented: Mat<f32, 3, 4> = a.hstack(&b)`.
+    ///
+    /// Use `DMat::hstack` when the result shape can't be named.
+    pub fn hstack<const C2: usize, const C3: usize>(&self, other: &Mat<T, R, C2>) -> Mat<T, R, C3> {
+        const { assert!(C + C2 == C3, "hstack result must have C + C2 columns") };
+        Mat::from_fn(|i, j| if j < C { self.data[i][j] } else { other.data[i][j - C] })
+    }
+
+    /// `[self; other]`, the result height is inferred and must equal `R + R2`.
+    ///
+    /// Use `DMat::vstack` when the result shape can't be named.
+    pub fn vstack<const R2: usize, const R3: usize>(&self, other: &Mat<T, R2, C>) -> Mat<T, R3, C> {
+        const { assert!(R + R2 == R3, "vstack result must have R + R2 rows") };
+        Mat::from_fn(|i, j| if i < R { self.data[i][j] } else { other.data[i - R][j] })
+    }
+
     pub fn flatten_row_major(&self) -> Vec<T> {
         let mut result = Vec::with_capacity(R * C);
         for i in 0..R {
@@ -254,33 +468,133 @@ where
         }
         result
     }
+
+    /// Stack the columns into one vector, `N` must equal `R * C`.
+    pub fn vec<const N: usize>(&self) -> Vector<T, N> {
+        const { assert!(R * C == N, "vec result must have R * C elements") };
+        Vector::from_fn(|k| self.data[k % R][k / R])
+    }
+
+    /// Inverse of `vec`, fill the columns from a stacked vector.
+    pub fn unvec<const N: usize>(v: &Vector<T, N>) -> Self {
+        const { assert!(R * C == N, "unvec input must have R * C elements") };
+        Self::from_fn(|i, j| v.data[j * R + i])
+    }
 }
 
 impl<T, const R: usize, const C: usize> Mat<T, R, C>
 where
-    T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    T: Mul<Output = T> + Copy,
 {
-    pub fn sum(&self) -> T {
-        let mut total = T::default();
-        for i in 0..R {
-            for j in 0..C {
-                total = total + self.data[i][j];
+    /// Kronecker product `self ⊗ other`, the result shape is inferred and must
+    /// equal `(R * R2) x (C * C2)`.
+    ///
+    /// Use `DMat::kronecker` when the result shape can't be named.
+    pub fn kronecker<const R2: usize, const C2: usize, const R3: usize, const C3: usize>(&self, other: &Mat<T, R2, C2>) -> Mat<T, R3, C3> {
+        const { assert!(R * R2 == R3 && C * C2 == C3, "kronecker result must be (R * R2) x (C * C2)") };
+        Mat::from_fn(|i, j| self.data[i / R2][j / C2] * other.data[i % R2][j % C2])
+    }
+
+    /// Outer product `a * bᵀ`.
+    pub fn outer(a: &Vector<T, R>, b: &Vector<T, C>) -> Self {
+        Self::from_fn(|i, j| a.data[i] * b.data[j])
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Zero + One + Copy,
+{
+    /// The commutation matrix `K` with `K * vec(A) = vec(Aᵀ)` for an `M x P`
+    /// matrix `A`, `N` must equal `M * P`.
+    pub fn commutation<const M: usize, const P: usize>() -> Self {
+        const { assert!(M * P == N, "commutation matrix must be (M * P) x (M * P)") };
+        Self::from_fn(|row, col| if row == (col % M) * P + col / M { T::one() } else { T::zero() })
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Zero + Copy,
+{
+    /// The KKT matrix `[[self, aᵀ], [a, 0]]` of an equality constrained quadratic
+    /// program, the result size is inferred and must equal `N + M`.
+    ///
+    /// Use `DMat::kkt` when the result shape can't be named.
+    pub fn kkt<const M: usize, const K: usize>(&self, a: &Mat<T, M, N>) -> Mat<T, K, K> {
+        const { assert!(N + M == K, "kkt result must have N + M rows") };
+        let mut result = Mat::<T, K, K>::default();
+        for i in 0..N {
+            for j in 0..N {
+                result.data[i][j] = self.data[i][j];
+            }
+        }
+        for i in 0..M {
+            for j in 0..N {
+                result.data[N + i][j] = a.data[i][j];
+                result.data[j][N + i] = a.data[i][j];
             }
         }
-        total
+        result
+    }
+}
+
+impl<T> Mat<T, 4, 4>
+where
+    T: Zero + One + Copy,
+{
+    /// Embed a 3x3 matrix as the upper-left block of a homogeneous transform.
+    pub fn from_mat3(mat: &Mat<T, 3, 3>) -> Self {
+        let mut result = Self::identity();
+        for i in 0..3 {
+            result.data[i][..3].copy_from_slice(&mat.data[i]);
+        }
+        result
+    }
+}
+
+impl<T> Mat<T, 3, 3>
+where
+    T: Zero + One + Copy,
+{
+    /// Embed a 2x2 matrix as the upper-left block of a homogeneous transform.
+    pub fn from_mat2(mat: &Mat<T, 2, 2>) -> Self {
+        let mut result = Self::identity();
+        for i in 0..2 {
+            result.data[i][..2].copy_from_slice(&mat.data[i]);
+        }
+        result
+    }
+
+    /// The upper-left 3x3 block.
+    pub fn from_mat4(mat: &Mat<T, 4, 4>) -> Self {
+        mat.submatrix::<0, 0, 3, 3>()
+    }
+}
+
+impl<T> Mat<T, 2, 2>
+where
+    T: Zero + One + Copy,
+{
+    /// The upper-left 2x2 block.
+    pub fn from_mat3(mat: &Mat<T, 3, 3>) -> Self {
+        mat.submatrix::<0, 0, 2, 2>()
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Clone,
+{
+    pub fn sum(&self) -> T {
+        self.iter().fold(T::zero(), |total, x| total + x.clone())
     }
     
     pub fn product(&self) -> T 
     where
-        T: From<u8>,
+        T: One,
     {
-        let mut total = T::from(1u8);
-        for i in 0..R {
-            for j in 0..C {
-                total = total * self.data[i][j];
-            }
-        }
-        total
+        self.iter().fold(T::one(), |total, x| total * x.clone())
     }
 }
 
@@ -318,20 +632,19 @@ impl<T, const R: usize, const C: usize> Mat<T, R, C> {
     }
 }
 
-impl<T, const R: usize, const C: usize> Mat<T, R, C>
-where
-    T: From<u8> + Copy,
-{
-    pub fn zeros() -> Self {
-        Self {
-            data: [[T::from(0u8); C]; R],
-        }
+impl<T, const R: usize, const C: usize> Mat<T, R, C> {
+    pub fn zeros() -> Self
+    where
+        T: Zero,
+    {
+        Self::from_fn(|_, _| T::zero())
     }
     
-    pub fn ones() -> Self {
-        Self {
-            data: [[T::from(1u8); C]; R],
-        }
+    pub fn ones() -> Self
+    where
+        T: One,
+    {
+        Self::from_fn(|_, _| T::one())
     }
 }
 
@@ -340,38 +653,30 @@ where
     T: Clone,
 {
     fn clone(&self) -> Self {
-        let mut data = unsafe { std::mem::MaybeUninit::<[[T; C]; R]>::uninit().assume_init() };
-        for i in 0..R {
-            for j in 0..C {
-                data[i][j] = self.data[i][j].clone();
-            }
-        }
-        Self { data }
+        Self { data: self.data.clone() }
     }
 }
 
 impl<T, const R: usize, const C: usize> Copy for Mat<T, R, C> where T: Copy {}
 
 impl<T, const R: usize, const C: usize> Default for Mat<T, R, C> 
-where T: Default + Copy 
+where T: Zero 
 {
     fn default() -> Self {
-        Self {
-            data: [[T::default(); C]; R],
-        }
+        Self::zeros()
     }
 }
 
 impl<T, const R: usize, const C: usize> Mat<T, R, C>
 where
-    T: Default + Copy,
+    T: Zero + Copy,
 {
     pub fn from_vec(vec: &[T]) -> Result<Self, &'static str> {
         if vec.len() != R * C {
             return Err("Vector length does not match matrix dimensions");
         }
         
-        let mut data = [[T::default(); C]; R];
+        let mut data = [[T::zero(); C]; R];
         for i in 0..R {
             for j in 0..C {
                 data[i][j] = vec[i * C + j];
@@ -386,7 +691,7 @@ where
             return Err("Vector length does not match matrix dimensions");
         }
         
-        let mut data = [[T::default(); C]; R];
+        let mut data = [[T::zero(); C]; R];
         for i in 0..R {
             for j in 0..C {
                 data[i][j] = vec[j * R + i];
@@ -1,2 +1,12 @@
+pub mod num;
+pub mod complex;
+pub mod rational;
+pub mod bigint;
+pub mod polynomial;
 pub mod matrix;
-pub mod f32;
\ No newline at end of file
+pub mod dmatrix;
+pub mod bitmatrix;
+pub mod vector;
+pub mod f32;
+pub mod f64;
+pub mod i32;
\ No newline at end of file
@@ -0,0 +1,242 @@
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg};
+
+/// Additive identity.
+pub trait Zero: Sized + Add<Output = Self> {
+    fn zero() -> Self;
+
+    fn is_zero(&self) -> bool;
+}
+
+/// Multiplicative identity.
+pub trait One: Sized + Mul<Output = Self> {
+    fn one() -> Self;
+}
+
+/// Commutative ring with identity, the element type of the generic matrix
+/// arithmetic. `Neg` is not required so that the unsigned integers qualify.
+pub trait Ring: Clone + PartialEq + Zero + One + Sub<Output = Self> {}
+
+/// Ring in which every non-zero element has a multiplicative inverse, the
+/// element type of elimination and inversion.
+pub trait Field: Ring + Neg<Output = Self> + Div<Output = Self> {
+    #[inline]
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    /// `(a + b i) / (c + d i)` as `(re, im)`, the division of `Complex`. The
+    /// default is the textbook formula through `c² + d²`, which is exact for
+    /// exact fields but overflows or underflows in floating point, where the
+    /// `Real` types scale the divisor first.
+    fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+        let denominator = c.clone() * c.clone() + d.clone() * d.clone();
+        let re = a.clone() * c.clone() + b.clone() * d.clone();
+        let im = b * c - a * d;
+        (re / denominator.clone(), im / denominator)
+    }
+}
+
+/// Signed integer with truncating division and overflow-checked arithmetic,
+/// the component type of `Rational`.
+pub trait Integer: Ring + Ord + Neg<Output = Self> + Div<Output = Self> + Rem<Output = Self> {
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    /// Non-negative greatest common divisor, `gcd(0, 0) = 0`. Panics if it is
+    /// not representable, e.g. `gcd(i64::MIN, 0)`.
+    fn gcd(&self, other: &Self) -> Self {
+        self.checked_gcd(other).expect("gcd overflow")
+    }
+
+    /// `gcd`, `None` if it is not representable.
+    fn checked_gcd(&self, other: &Self) -> Option<Self> {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            // `MIN % -1` overflows
+            let r = if b == -Self::one() { Self::zero() } else { a % b.clone() };
+            a = b;
+            b = r;
+        }
+        if a < Self::zero() { Self::zero().checked_sub(&a) } else { Some(a) }
+    }
+}
+
+/// Ordered field with floating point semantics, used by the numerical
+/// algorithms on `Mat`.
+pub trait Real: Field + Copy + PartialOrd {
+    fn epsilon() -> Self;
+
+    fn from_f64(value: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    fn is_finite(self) -> bool;
+
+    fn exp(self) -> Self;
+
+    fn ln(self) -> Self;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    fn atan2(self, other: Self) -> Self;
+
+    fn powf(self, n: Self) -> Self;
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        if other > self { other } else { self }
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+}
+
+macro_rules! impl_ring {
+    ($($t:ident),*) => {
+        $(
+            impl Zero for $t {
+                #[inline]
+                fn zero() -> Self {
+                    0 as $t
+                }
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    *self == 0 as $t
+                }
+            }
+
+            impl One for $t {
+                #[inline]
+                fn one() -> Self {
+                    1 as $t
+                }
+            }
+
+            impl Ring for $t {}
+        )*
+    };
+}
+
+impl_ring!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+macro_rules! impl_integer {
+    ($($t:ident),*) => {
+        $(
+            impl Integer for $t {
+                #[inline]
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    $t::checked_add(*self, *other)
+                }
+
+                #[inline]
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    $t::checked_sub(*self, *other)
+                }
+
+                #[inline]
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    $t::checked_mul(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_field_float {
+    ($($t:ident),*) => {
+        $(
+            impl Field for $t {
+                #[inline]
+                fn complex_div(a: Self, b: Self, c: Self, d: Self) -> (Self, Self) {
+                    crate::base::complex::scaled_div(a, b, c, d)
+                }
+            }
+        )*
+    };
+}
+
+impl_field_float!(f32, f64);
+
+macro_rules! impl_real {
+    ($($t:ident),*) => {
+        $(
+            impl Real for $t {
+                #[inline]
+                fn epsilon() -> Self {
+                    $t::EPSILON
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+
+                #[inline]
+                fn is_finite(self) -> bool {
+                    $t::is_finite(self)
+                }
+
+                #[inline]
+                fn exp(self) -> Self {
+                    $t::exp(self)
+                }
+
+                #[inline]
+                fn ln(self) -> Self {
+                    $t::ln(self)
+                }
+
+                #[inline]
+                fn sin(self) -> Self {
+                    $t::sin(self)
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    $t::cos(self)
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    $t::atan2(self, other)
+                }
+
+                #[inline]
+                fn powf(self, n: Self) -> Self {
+                    $t::powf(self, n)
+                }
+            }
+        )*
+    };
+}
+
+impl_real!(f32, f64);
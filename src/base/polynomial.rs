@@ -0,0 +1,475 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Rem, RemAssign, Neg};
+use std::fmt;
+
+use crate::base::complex::Complex;
+use crate::base::dmatrix::DMat;
+use crate::base::num::{Field, One, Real, Ring, Zero};
+
+const ABERTH_MAX_ITERATIONS: usize = 500;
+
+/// Univariate polynomial `c₀ + c₁x + … + cₙxⁿ`.
+///
+/// The coefficients are stored in ascending order with no trailing zeros, so
+/// the zero polynomial has no coefficients and `degree` is `None`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Polynomial<T> {
+    coeffs: Vec<T>,
+}
+
+impl<T> Polynomial<T>
+where
+    T: Zero,
+{
+    /// From the coefficients in ascending order, trailing zeros are dropped.
+    pub fn new(mut coeffs: Vec<T>) -> Self {
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        Self { coeffs }
+    }
+
+    /// Coefficients in ascending order, empty for the zero polynomial.
+    #[inline]
+    pub fn coeffs(&self) -> &[T] {
+        &self.coeffs
+    }
+
+    #[inline]
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    #[inline]
+    pub fn leading(&self) -> Option<&T> {
+        self.coeffs.last()
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Ring,
+{
+    /// Monic polynomial `(x - r₀)(x - r₁)…` with the given roots.
+    pub fn from_roots(roots: &[T]) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        roots.iter().fold(Self::one(), |p, r| p * Self::new(vec![-r.clone(), T::one()]))
+    }
+
+    /// Value at `x` by Horner's scheme.
+    pub fn eval(&self, x: T) -> T {
+        self.coeffs.iter().rev().fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    pub fn derivative(&self) -> Self {
+        let mut factor = T::zero();
+        let coeffs = self.coeffs.iter().skip(1).map(|c| {
+            factor = factor.clone() + T::one();
+            c.clone() * factor.clone()
+        });
+        Self::new(coeffs.collect())
+    }
+
+    /// `self(other(x))` by Horner's scheme.
+    pub fn compose(&self, other: &Self) -> Self {
+        self.coeffs.iter().rev().fold(Self::zero(), |acc, c| &(&acc * other) + &Self::new(vec![c.clone()]))
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Field,
+{
+    /// Antiderivative with zero constant term.
+    pub fn integral(&self) -> Self {
+        let mut factor = T::zero();
+        let coeffs = self.coeffs.iter().map(|c| {
+            factor = factor.clone() + T::one();
+            c.clone() / factor.clone()
+        });
+        Self::new(std::iter::once(T::zero()).chain(coeffs).collect())
+    }
+
+    /// Quotient and remainder of long division, `deg r < deg divisor`.
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let lead = divisor.leading().expect("Polynomial division by zero").clone();
+        let d = divisor.coeffs.len();
+        if self.coeffs.len() < d {
+            return (Self::zero(), self.clone());
+        }
+
+        let mut rem = self.coeffs.clone();
+        let mut quot = vec![T::zero(); rem.len() + 1 - d];
+        for k in (0..quot.len()).rev() {
+            let q = rem[k + d - 1].clone() / lead.clone();
+            for (j, c) in divisor.coeffs.iter().enumerate() {
+                rem[k + j] = rem[k + j].clone() - q.clone() * c.clone();
+            }
+            quot[k] = q;
+        }
+        rem.truncate(d - 1);
+        (Self::new(quot), Self::new(rem))
+    }
+
+    /// Monic greatest common divisor by the Euclidean algorithm, zero if both
+    /// are zero.
+    ///
+    /// Exact for exact fields such as `Rational` or `Fp`, with floating point
+    /// coefficients the remainders rarely vanish exactly.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+        match a.leading() {
+            Some(lead) => {
+                let scale = T::one() / lead.clone();
+                a * scale
+            }
+            None => a,
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Real,
+{
+    /// All complex roots with multiplicity by the Aberth–Ehrlich iteration,
+    /// sorted by real then imaginary part with NaN last. Empty for constant
+    /// polynomials.
+    ///
+    /// Simple roots converge cubically, multiple roots only to about
+    /// `eps^(1/m)` for multiplicity `m`.
+    pub fn roots(&self) -> Vec<Complex<T>> {
+        let zero = T::zero();
+        // exact roots at zero first, they would stall the iteration
+        let shift = self.coeffs.iter().take_while(|c| c.is_zero()).count();
+        let mut roots = vec![Complex::new(zero, zero); shift.min(self.degree().unwrap_or(0))];
+        let Some(lead) = self.leading() else {
+            return roots;
+        };
+        let monic: Vec<Complex<T>> = self.coeffs[shift..].iter().map(|&c| Complex::from(c / *lead)).collect();
+        let n = monic.len() - 1;
+        if n == 0 {
+            return roots;
+        }
+
+        // start on a circle of the geometric mean radius, rotated off the axes
+        let radius = monic[0].abs().powf(T::one() / T::from_f64(n as f64));
+        let mut z: Vec<Complex<T>> = (0..n)
+            .map(|k| {
+                let angle = T::from_f64(2.0 * std::f64::consts::PI * k as f64 / n as f64 + 0.4);
+                Complex::from_polar(radius, angle)
+            })
+            .collect();
+
+        let tolerance = T::from_f64(4.0) * T::epsilon();
+        let nudge = Complex::from_polar(T::epsilon().sqrt(), T::from_f64(1.0));
+        for _ in 0..ABERTH_MAX_ITERATIONS {
+            let mut converged = true;
+            for i in 0..n {
+                let (p, dp) = horner_with_derivative(&monic, z[i]);
+                if p.is_zero() {
+                    continue;
+                }
+                if dp.is_zero() {
+                    // a critical point that is not a root, move off it and retry
+                    let offset = nudge * (T::one() + z[i].abs());
+                    z[i] += offset;
+                    converged = false;
+                    continue;
+                }
+                let ratio = p / dp;
+                let mut repulsion = Complex::<T>::zero();
+                for j in (0..n).filter(|&j| j != i) {
+                    repulsion += (z[i] - z[j]).recip();
+                }
+                let step = ratio / (Complex::one() - ratio * repulsion);
+                z[i] -= step;
+                // a non-finite step is never converged
+                if !step.abs().is_finite() || step.abs() > tolerance * z[i].abs() {
+                    converged = false;
+                }
+            }
+            if converged {
+                break;
+            }
+        }
+
+        roots.extend(z);
+        roots.sort_by(|a, b| nan_last_cmp(a.re, b.re).then(nan_last_cmp(a.im, b.im)));
+        roots
+    }
+
+    /// Real parts of the roots whose imaginary part is at most `tolerance`
+    /// relative to `max(1, |z|)`, sorted ascending.
+    pub fn real_roots(&self, tolerance: T) -> Vec<T> {
+        self.roots()
+            .into_iter()
+            .filter(|z| z.im.abs() <= tolerance * z.abs().max(T::one()))
+            .map(|z| z.re)
+            .collect()
+    }
+
+    /// Least-squares fit of degree `degree` to the points `(x[i], y[i])`, by
+    /// QR factorization of the Vandermonde matrix.
+    pub fn polyfit(x: &[T], y: &[T], degree: usize) -> Result<Self, &'static str> {
+        if x.len() != y.len() {
+            return Err("Lengths do not match");
+        }
+        if x.len() <= degree {
+            return Err("Not enough points for the degree");
+        }
+        let a = DMat::vandermonde(x, degree + 1);
+        let b = DMat::from_fn(y.len(), 1, |i, _| y[i]);
+        let coeffs = a.solve_least_squares(&b).ok_or("Vandermonde matrix is rank deficient")?;
+        Ok(Self::new(coeffs.into_vec()))
+    }
+}
+
+/// Total order on `T` with every NaN equal and after all numbers.
+fn nan_last_cmp<T>(a: T, b: T) -> Ordering
+where
+    T: Real,
+{
+    #[allow(clippy::eq_op)]
+    let (a_nan, b_nan) = (a != a, b != b);
+    a.partial_cmp(&b).unwrap_or(a_nan.cmp(&b_nan))
+}
+
+/// `p(z)` and `p'(z)` in one Horner pass.
+fn horner_with_derivative<T>(coeffs: &[Complex<T>], z: Complex<T>) -> (Complex<T>, Complex<T>)
+where
+    T: Real,
+{
+    let mut p = Complex::zero();
+    let mut dp = Complex::zero();
+    for &c in coeffs.iter().rev() {
+        dp = dp * z + p;
+        p = p * z + c;
+    }
+    (p, dp)
+}
+
+impl<T> Add<&Polynomial<T>> for &Polynomial<T>
+where
+    T: Ring,
+{
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: &Polynomial<T>) -> Self::Output {
+        let n = self.coeffs.len().max(rhs.coeffs.len());
+        let coeffs = (0..n).map(|i| match (self.coeffs.get(i), rhs.coeffs.get(i)) {
+            (Some(a), Some(b)) => a.clone() + b.clone(),
+            (Some(a), None) => a.clone(),
+            (None, Some(b)) => b.clone(),
+            (None, None) => unreachable!(),
+        });
+        Polynomial::new(coeffs.collect())
+    }
+}
+
+impl<T> Sub<&Polynomial<T>> for &Polynomial<T>
+where
+    T: Ring,
+{
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: &Polynomial<T>) -> Self::Output {
+        let n = self.coeffs.len().max(rhs.coeffs.len());
+        let coeffs = (0..n).map(|i| {
+            let a = self.coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            let b = rhs.coeffs.get(i).cloned().unwrap_or_else(T::zero);
+            a - b
+        });
+        Polynomial::new(coeffs.collect())
+    }
+}
+
+impl<T> Mul<&Polynomial<T>> for &Polynomial<T>
+where
+    T: Ring,
+{
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: &Polynomial<T>) -> Self::Output {
+        if self.coeffs.is_empty() || rhs.coeffs.is_empty() {
+            return Polynomial::zero();
+        }
+        let mut coeffs = vec![T::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] = coeffs[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<T> Div<&Polynomial<T>> for &Polynomial<T>
+where
+    T: Field,
+{
+    type Output = Polynomial<T>;
+
+    fn div(self, rhs: &Polynomial<T>) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<T> Rem<&Polynomial<T>> for &Polynomial<T>
+where
+    T: Field,
+{
+    type Output = Polynomial<T>;
+
+    fn rem(self, rhs: &Polynomial<T>) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<T> Neg for Polynomial<T>
+where
+    T: Ring + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { coeffs: self.coeffs.into_iter().map(|c| -c).collect() }
+    }
+}
+
+impl<T> Mul<T> for Polynomial<T>
+where
+    T: Ring,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::new(self.coeffs.into_iter().map(|c| c * scalar.clone()).collect())
+    }
+}
+
+macro_rules! forward_binop {
+    ($($bound:ident: $op:ident, $method:ident, $assign:ident, $assign_method:ident);*) => {
+        $(
+            impl<T> $op for Polynomial<T>
+            where
+                T: $bound,
+            {
+                type Output = Polynomial<T>;
+
+                fn $method(self, rhs: Polynomial<T>) -> Polynomial<T> {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl<T> $op<&Polynomial<T>> for Polynomial<T>
+            where
+                T: $bound,
+            {
+                type Output = Polynomial<T>;
+
+                fn $method(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl<T> $assign for Polynomial<T>
+            where
+                T: $bound,
+            {
+                fn $assign_method(&mut self, rhs: Polynomial<T>) {
+                    *self = (&*self).$method(&rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward_binop!(
+    Ring: Add, add, AddAssign, add_assign;
+    Ring: Sub, sub, SubAssign, sub_assign;
+    Ring: Mul, mul, MulAssign, mul_assign;
+    Field: Div, div, DivAssign, div_assign;
+    Field: Rem, rem, RemAssign, rem_assign
+);
+
+impl<T> Zero for Polynomial<T>
+where
+    T: Ring,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self { coeffs: Vec::new() }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl<T> One for Polynomial<T>
+where
+    T: Ring,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::new(vec![T::one()])
+    }
+}
+
+impl<T> Ring for Polynomial<T> where T: Ring {}
+
+impl<T> Default for Polynomial<T>
+where
+    T: Ring,
+{
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T> From<T> for Polynomial<T>
+where
+    T: Zero,
+{
+    fn from(value: T) -> Self {
+        Self::new(vec![value])
+    }
+}
+
+impl<T> fmt::Display for Polynomial<T>
+where
+    T: Ring + fmt::Display,
+{
+    /// Descending powers, e.g. `2x^2 + x + -3`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.coeffs.is_empty() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (k, c) in self.coeffs.iter().enumerate().rev().filter(|(_, c)| !c.is_zero()) {
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            match (k, *c == T::one()) {
+                (0, _) => write!(f, "{}", c)?,
+                (1, true) => write!(f, "x")?,
+                (1, false) => write!(f, "{}x", c)?,
+                (_, true) => write!(f, "x^{}", k)?,
+                (_, false) => write!(f, "{}x^{}", c, k)?,
+            }
+        }
+        Ok(())
+    }
+}
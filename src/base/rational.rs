@@ -0,0 +1,295 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+use std::fmt;
+
+use crate::base::num::{Field, Integer, One, Ring, Zero};
+
+/// Exact fraction `num / den`, always stored in lowest terms with `den > 0`.
+///
+/// The operators panic when a component overflows `T`, the `checked_*`
+/// methods return `None` instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    num: T,
+    den: T,
+}
+
+impl<T> Rational<T>
+where
+    T: Integer,
+{
+    /// Panics if `den` is zero.
+    pub fn new(num: T, den: T) -> Self {
+        Self::checked_new(num, den).expect("Rational overflow")
+    }
+
+    /// `None` if the sign normalization overflows, panics if `den` is zero.
+    pub fn checked_new(num: T, den: T) -> Option<Self> {
+        assert!(!den.is_zero(), "Rational with zero denominator");
+        let g = num.checked_gcd(&den)?;
+        let (mut num, mut den) = (num / g.clone(), den / g);
+        if den < T::zero() {
+            num = T::zero().checked_sub(&num)?;
+            den = T::zero().checked_sub(&den)?;
+        }
+        Some(Self { num, den })
+    }
+
+    #[inline]
+    pub fn from_integer(value: T) -> Self {
+        Self { num: value, den: T::one() }
+    }
+
+    #[inline]
+    pub fn numer(&self) -> &T {
+        &self.num
+    }
+
+    /// Always positive.
+    #[inline]
+    pub fn denom(&self) -> &T {
+        &self.den
+    }
+
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.den == T::one()
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.num < T::zero() { -self.clone() } else { self.clone() }
+    }
+
+    /// Panics if `self` is zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.den.clone(), self.num.clone())
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        // add over the least common denominator to keep the intermediates small
+        let g = self.den.checked_gcd(&other.den)?;
+        let (a, b) = (other.den.clone() / g.clone(), self.den.clone() / g);
+        let num = self.num.checked_mul(&a)?.checked_add(&other.num.checked_mul(&b)?)?;
+        Self::checked_new(num, b.checked_mul(&other.den)?)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&Self { num: T::zero().checked_sub(&other.num)?, den: other.den.clone() })
+    }
+
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // cancel across before multiplying, the result is then in lowest terms
+        let g1 = self.num.checked_gcd(&other.den)?;
+        let g2 = other.num.checked_gcd(&self.den)?;
+        let num = (self.num.clone() / g1.clone()).checked_mul(&(other.num.clone() / g2.clone()))?;
+        let den = (self.den.clone() / g2).checked_mul(&(other.den.clone() / g1))?;
+        Some(Self { num, den })
+    }
+
+    /// Panics if `other` is zero.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        assert!(!other.is_zero(), "Rational division by zero");
+        let recip = Self::checked_new(other.den.clone(), other.num.clone())?;
+        self.checked_mul(&recip)
+    }
+}
+
+impl<T> From<T> for Rational<T>
+where
+    T: Integer,
+{
+    fn from(value: T) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+impl<T> Add for Rational<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T> Sub for Rational<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T> Mul for Rational<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T> Div for Rational<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.checked_div(&rhs).expect("Rational overflow")
+    }
+}
+
+impl<T> Neg for Rational<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let num = T::zero().checked_sub(&self.num).expect("Rational overflow");
+        Self { num, den: self.den }
+    }
+}
+
+impl<T> AddAssign for Rational<T>
+where
+    T: Integer,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<T> SubAssign for Rational<T>
+where
+    T: Integer,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<T> MulAssign for Rational<T>
+where
+    T: Integer,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T> DivAssign for Rational<T>
+where
+    T: Integer,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+impl<T> PartialOrd for Rational<T>
+where
+    T: Integer,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Rational<T>
+where
+    T: Integer,
+{
+    /// By the cross products, or by the continued fraction expansions when
+    /// these overflow.
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let (Some(lhs), Some(rhs)) = (self.num.checked_mul(&other.den), other.num.checked_mul(&self.den)) {
+            return lhs.cmp(&rhs);
+        }
+        let (mut a, mut b) = (self.num.clone(), self.den.clone());
+        let (mut c, mut d) = (other.num.clone(), other.den.clone());
+        loop {
+            let (q1, r1) = floor_div_rem(&a, &b);
+            let (q2, r2) = floor_div_rem(&c, &d);
+            if q1 != q2 {
+                return q1.cmp(&q2);
+            }
+            match (r1.is_zero(), r2.is_zero()) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                (false, false) => {}
+            }
+            // r1 / b against r2 / d is d / r2 against b / r1
+            (a, b, c, d) = (d, r2, b, r1);
+        }
+    }
+}
+
+/// `(q, r)` with `a = q b + r` and `0 <= r < b`, for `b > 0`.
+fn floor_div_rem<T>(a: &T, b: &T) -> (T, T)
+where
+    T: Integer,
+{
+    let (q, r) = (a.clone() / b.clone(), a.clone() % b.clone());
+    if r < T::zero() { (q - T::one(), r + b.clone()) } else { (q, r) }
+}
+
+impl<T> Zero for Rational<T>
+where
+    T: Integer,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+}
+
+impl<T> One for Rational<T>
+where
+    T: Integer,
+{
+    #[inline]
+    fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+}
+
+impl<T> Ring for Rational<T> where T: Integer {}
+
+impl<T> Field for Rational<T> where T: Integer {}
+
+impl<T> Default for Rational<T>
+where
+    T: Integer,
+{
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T> fmt::Display for Rational<T>
+where
+    T: Integer + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg, Index, IndexMut};
+use std::fmt;
+
+use crate::base::matrix::Mat;
+use crate::base::num::{Real, Zero};
+
+pub struct Vector<T, const N: usize> {
+    pub data: [T; N],
+}
+
+impl<T, const N: usize> Vector<T, N> {
+
+    #[inline]
+    pub fn new(data: [T; N]) -> Self {
+        Self { data }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self { data: std::array::from_fn(f) }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    pub fn map<U, F>(self, f: F) -> Vector<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        Vector { data: self.data.map(f) }
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    pub fn dot(&self, other: &Self) -> T {
+        let mut total = T::zero();
+        for i in 0..N {
+            total = total + self.data[i] * other.data[i];
+        }
+        total
+    }
+
+    #[inline]
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    pub fn sum(&self) -> T {
+        let mut total = T::zero();
+        for i in 0..N {
+            total = total + self.data[i];
+        }
+        total
+    }
+
+    /// Outer product `self * otherᵀ`.
+    pub fn outer<const M: usize>(&self, other: &Vector<T, M>) -> Mat<T, N, M> {
+        let mut result = Mat::<T, N, M>::default();
+        for i in 0..N {
+            for j in 0..M {
+                result.data[i][j] = self.data[i] * other.data[j];
+            }
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Copy,
+{
+    /// The vector as an `N x 1` column matrix.
+    pub fn to_col(&self) -> Mat<T, N, 1> {
+        Mat { data: self.data.map(|x| [x]) }
+    }
+
+    /// The vector as a `1 x N` row matrix.
+    pub fn to_row(&self) -> Mat<T, 1, N> {
+        Mat { data: [self.data] }
+    }
+
+    pub fn from_col(mat: &Mat<T, N, 1>) -> Self {
+        Self { data: mat.data.map(|row| row[0]) }
+    }
+
+    pub fn from_row(mat: &Mat<T, 1, N>) -> Self {
+        Self { data: mat.data[0] }
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Real,
+{
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        *self / self.norm()
+    }
+}
+
+macro_rules! impl_scalar_mul_vector {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize> Mul<Vector<$t, N>> for $t {
+                type Output = Vector<$t, N>;
+
+                fn mul(self, rhs: Vector<$t, N>) -> Self::Output {
+                    rhs * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_mul_vector!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T, const N: usize> Add for Vector<T, N>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        for i in 0..N {
+            result.data[i] = result.data[i] + rhs.data[i];
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> AddAssign for Vector<T, N>
+where
+    T: AddAssign + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        for i in 0..N {
+            self.data[i] += rhs.data[i];
+        }
+    }
+}
+
+impl<T, const N: usize> Sub for Vector<T, N>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        for i in 0..N {
+            result.data[i] = result.data[i] - rhs.data[i];
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> SubAssign for Vector<T, N>
+where
+    T: SubAssign + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        for i in 0..N {
+            self.data[i] -= rhs.data[i];
+        }
+    }
+}
+
+impl<T, const N: usize> Neg for Vector<T, N>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { data: self.data.map(|x| -x) }
+    }
+}
+
+impl<T, const N: usize> Mul<T> for Vector<T, N>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self { data: self.data.map(|x| x * scalar) }
+    }
+}
+
+impl<T, const N: usize> MulAssign<T> for Vector<T, N>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, scalar: T) {
+        for i in 0..N {
+            self.data[i] *= scalar;
+        }
+    }
+}
+
+impl<T, const N: usize> Mul for Vector<T, N>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        for i in 0..N {
+            result.data[i] = result.data[i] * rhs.data[i];
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Div<T> for Vector<T, N>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self::Output {
+        Self { data: self.data.map(|x| x / scalar) }
+    }
+}
+
+impl<T, const N: usize> DivAssign<T> for Vector<T, N>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, scalar: T) {
+        for i in 0..N {
+            self.data[i] /= scalar;
+        }
+    }
+}
+
+impl<T, const N: usize> Div for Vector<T, N>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let mut result = self;
+        for i in 0..N {
+            result.data[i] = result.data[i] / rhs.data[i];
+        }
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<Vector<T, C>> for Mat<T, R, C>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    type Output = Vector<T, R>;
+
+    fn mul(self, rhs: Vector<T, C>) -> Self::Output {
+        let mut result = [T::zero(); R];
+        for (out, row) in result.iter_mut().zip(&self.data) {
+            for (&a, &b) in row.iter().zip(&rhs.data) {
+                *out = *out + a * b;
+            }
+        }
+        Vector { data: result }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<Mat<T, R, C>> for Vector<T, R>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    type Output = Vector<T, C>;
+
+    fn mul(self, rhs: Mat<T, R, C>) -> Self::Output {
+        let mut result = [T::zero(); C];
+        for (j, out) in result.iter_mut().enumerate() {
+            for (&a, row) in self.data.iter().zip(&rhs.data) {
+                *out = *out + a * row[j];
+            }
+        }
+        Vector { data: result }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(data: [T; N]) -> Self {
+        Self { data }
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(vector: Vector<T, N>) -> Self {
+        vector.data
+    }
+}
+
+impl<T, const N: usize> From<Mat<T, N, 1>> for Vector<T, N>
+where
+    T: Copy,
+{
+    fn from(mat: Mat<T, N, 1>) -> Self {
+        Self::from_col(&mat)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for Mat<T, N, 1>
+where
+    T: Copy,
+{
+    fn from(vector: Vector<T, N>) -> Self {
+        vector.to_col()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Vector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T, const N: usize> PartialEq for Vector<T, N>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T, const N: usize> Clone for Vector<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self { data: self.data.clone() }
+    }
+}
+
+impl<T, const N: usize> Copy for Vector<T, N> where T: Copy {}
+
+impl<T, const N: usize> Default for Vector<T, N>
+where
+    T: Zero + Copy,
+{
+    fn default() -> Self {
+        Self { data: [T::zero(); N] }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for Vector<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Vector{} [", N)?;
+        for x in &self.data {
+            write!(f, " {:?} ", x)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T, const N: usize> fmt::Display for Vector<T, N>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for x in &self.data {
+            write!(f, " {} ", x)?;
+        }
+        write!(f, "]")
+    }
+}
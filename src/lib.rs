@@ -1 +1,8 @@
-pub mod base;
\ No newline at end of file
+// lets the code generated by macro_utils name this crate from inside it
+extern crate self as gk_math;
+
+pub mod base;
+pub mod linalg;
+pub mod number;
+pub mod autodiff;
+pub mod optimize;
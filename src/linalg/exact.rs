@@ -0,0 +1,142 @@
+use crate::base::matrix::Mat;
+use crate::base::num::{Field, Integer};
+use crate::base::vector::Vector;
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Integer,
+{
+    /// Determinant by Bareiss fraction-free elimination, every intermediate is
+    /// a minor of the matrix so the divisions are exact.
+    ///
+    /// Panics if an intermediate minor overflows `T`.
+    pub fn det_bareiss(&self) -> T {
+        let mut a = self.clone();
+        let mut negate = false;
+        let mut prev = T::one();
+
+        for k in 0..N.saturating_sub(1) {
+            if a.data[k][k].is_zero() {
+                match (k + 1..N).find(|&i| !a.data[i][k].is_zero()) {
+                    Some(i) => {
+                        a.data.swap(k, i);
+                        negate = !negate;
+                    }
+                    None => return T::zero(),
+                }
+            }
+
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    let lhs = a.data[i][j].checked_mul(&a.data[k][k]);
+                    let rhs = a.data[i][k].checked_mul(&a.data[k][j]);
+                    let minor = lhs.zip(rhs).and_then(|(lhs, rhs)| lhs.checked_sub(&rhs));
+                    a.data[i][j] = minor.expect("Bareiss overflow") / prev.clone();
+                }
+            }
+            prev = a.data[k][k].clone();
+        }
+
+        let det = if N == 0 { T::one() } else { a.data[N - 1][N - 1].clone() };
+        if negate { -det } else { det }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Field + Clone,
+{
+    /// Reduced row echelon form and the pivot columns, by Gauss–Jordan
+    /// elimination pivoting on the first non-zero element.
+    ///
+    /// The result is exact for exact fields such as `Rational`, for floating
+    /// point use the magnitude-pivoting `solve` instead.
+    pub fn rref(&self) -> (Self, Vec<usize>) {
+        let mut a = self.clone();
+        let mut pivots = Vec::new();
+
+        for col in 0..C {
+            let row = pivots.len();
+            if row == R {
+                break;
+            }
+            let Some(pivot) = (row..R).find(|&i| !a.data[i][col].is_zero()) else {
+                continue;
+            };
+            a.data.swap(row, pivot);
+
+            let scale = T::one() / a.data[row][col].clone();
+            for j in col..C {
+                a.data[row][j] = a.data[row][j].clone() * scale.clone();
+            }
+            for i in 0..R {
+                if i == row || a.data[i][col].is_zero() {
+                    continue;
+                }
+                let factor = a.data[i][col].clone();
+                for j in col..C {
+                    a.data[i][j] = a.data[i][j].clone() - factor.clone() * a.data[row][j].clone();
+                }
+            }
+            pivots.push(col);
+        }
+
+        (a, pivots)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rref().1.len()
+    }
+
+    /// Basis of the solutions of `A x = 0`, one vector per free column of the
+    /// reduced row echelon form, empty for full column rank.
+    pub fn nullspace(&self) -> Vec<Vector<T, C>> {
+        let (a, pivots) = self.rref();
+        (0..C)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut x = Vector::from_fn(|j| if j == free { T::one() } else { T::zero() });
+                for (row, &col) in pivots.iter().enumerate() {
+                    x.data[col] = -a.data[row][free].clone();
+                }
+                x
+            })
+            .collect()
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Field + Clone,
+{
+    /// Inverse by Gauss–Jordan elimination pivoting on the first non-zero
+    /// element, `None` if the matrix is singular. Exact for exact fields.
+    pub fn inverse_exact(&self) -> Option<Self> {
+        let mut a = self.clone();
+        let mut inv = Self::from_fn(|i, j| if i == j { T::one() } else { T::zero() });
+
+        for col in 0..N {
+            let pivot = (col..N).find(|&i| !a.data[i][col].is_zero())?;
+            a.data.swap(col, pivot);
+            inv.data.swap(col, pivot);
+
+            let scale = T::one() / a.data[col][col].clone();
+            for j in 0..N {
+                a.data[col][j] = a.data[col][j].clone() * scale.clone();
+                inv.data[col][j] = inv.data[col][j].clone() * scale.clone();
+            }
+            for i in 0..N {
+                if i == col || a.data[i][col].is_zero() {
+                    continue;
+                }
+                let factor = a.data[i][col].clone();
+                for j in 0..N {
+                    a.data[i][j] = a.data[i][j].clone() - factor.clone() * a.data[col][j].clone();
+                    inv.data[i][j] = inv.data[i][j].clone() - factor.clone() * inv.data[col][j].clone();
+                }
+            }
+        }
+
+        Some(inv)
+    }
+}
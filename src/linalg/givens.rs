@@ -0,0 +1,54 @@
+use crate::base::matrix::Mat;
+use crate::base::num::Real;
+
+/// Plane rotation `G = [[c, s], [-s, c]]` acting on two rows or columns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Givens<T> {
+    pub c: T,
+    pub s: T,
+}
+
+impl<T> Givens<T>
+where
+    T: Real,
+{
+    /// Rotation with `G [a, b]ᵀ = [r, 0]ᵀ` and `r = hypot(a, b)`.
+    pub fn new(a: T, b: T) -> Self {
+        let zero = T::zero();
+        if b == zero {
+            return Self { c: T::one(), s: zero };
+        }
+        // scale before squaring to avoid overflow
+        let scale = a.abs().max(b.abs());
+        let (a, b) = (a / scale, b / scale);
+        let r = (a * a + b * b).sqrt();
+        Self { c: a / r, s: b / r }
+    }
+
+    /// `A ← G A`, rotating rows `i` and `k`.
+    pub fn apply_left<const R: usize, const C: usize>(&self, a: &mut Mat<T, R, C>, i: usize, k: usize) {
+        assert!(i < R && k < R && i != k, "Index out of range");
+        for j in 0..C {
+            let (x, y) = (a.data[i][j], a.data[k][j]);
+            a.data[i][j] = self.c * x + self.s * y;
+            a.data[k][j] = self.c * y - self.s * x;
+        }
+    }
+
+    /// `A ← A Gᵀ`, rotating columns `i` and `k`, so that `G A Gᵀ` is a similarity transform.
+    pub fn apply_right<const R: usize, const C: usize>(&self, a: &mut Mat<T, R, C>, i: usize, k: usize) {
+        assert!(i < C && k < C && i != k, "Index out of range");
+        for r in 0..R {
+            let (x, y) = (a.data[r][i], a.data[r][k]);
+            a.data[r][i] = self.c * x + self.s * y;
+            a.data[r][k] = self.c * y - self.s * x;
+        }
+    }
+
+    /// The explicit `N x N` rotation in the plane of `i` and `k`.
+    pub fn to_mat<const N: usize>(&self, i: usize, k: usize) -> Mat<T, N, N> {
+        let mut result = Mat::identity();
+        self.apply_left(&mut result, i, k);
+        result
+    }
+}
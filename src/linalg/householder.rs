@@ -0,0 +1,122 @@
+use crate::base::dmatrix::DMat;
+use crate::base::matrix::Mat;
+use crate::base::num::Real;
+use crate::base::vector::Vector;
+
+/// Householder reflection `H = I - beta v vᵀ`, symmetric and orthogonal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Householder<T, const N: usize> {
+    pub v: Vector<T, N>,
+    pub beta: T,
+}
+
+impl<T, const N: usize> Householder<T, N>
+where
+    T: Real,
+{
+    /// Reflection mapping `x` onto a multiple of the first unit vector, `H x = ∓‖x‖ e₀`.
+    pub fn from_vector(x: &Vector<T, N>) -> Self {
+        Self::from_subvector(x, 0)
+    }
+
+    /// Reflection acting on the components `start..N` only, mapping `x` onto a
+    /// multiple of `e_start` while leaving the components before `start` untouched.
+    /// This is the step of a QR or Hessenberg reduction on column `start`.
+    pub fn from_subvector(x: &Vector<T, N>, start: usize) -> Self {
+        assert!(start < N, "Index out of range");
+        let mut v = Vector::from_fn(|i| if i < start { T::zero() } else { x[i] });
+        let beta = reflector(&mut v.data[start..]);
+        Self { v, beta }
+    }
+
+    /// `A ← H A` without forming `H`.
+    pub fn apply_left<const C: usize>(&self, a: &mut Mat<T, N, C>) {
+        reflect_rows(a, &self.v.data, self.beta, 0);
+    }
+
+    /// `A ← A H` without forming `H`.
+    pub fn apply_right<const R: usize>(&self, a: &mut Mat<T, R, N>) {
+        reflect_cols(a, &self.v.data, self.beta, 0);
+    }
+
+    /// The explicit reflection matrix.
+    pub fn to_mat(&self) -> Mat<T, N, N> {
+        Mat::identity() - Mat::outer(&self.v, &self.v) * self.beta
+    }
+}
+
+/// Turn `x` into the vector `v` of the reflection `I - beta v vᵀ` mapping `x`
+/// onto a multiple of `e₀`, and return `beta`.
+pub(crate) fn reflector<T>(x: &mut [T]) -> T
+where
+    T: Real,
+{
+    let zero = T::zero();
+    let sigma = x[1..].iter().fold(zero, |acc, &e| acc + e * e);
+    if sigma == zero {
+        return zero;
+    }
+
+    // reflect onto -sign(x₀) ‖x‖ to avoid cancellation in v₀
+    let alpha = x[0];
+    let norm = (alpha * alpha + sigma).sqrt();
+    x[0] = if alpha < zero { alpha - norm } else { alpha + norm };
+    (T::one() + T::one()) / (x[0] * x[0] + sigma)
+}
+
+/// Row-major matrix storage the reflections can be applied to in place.
+pub(crate) trait Entries<T> {
+    fn shape(&self) -> (usize, usize);
+
+    fn entry(&mut self, i: usize, j: usize) -> &mut T;
+}
+
+impl<T, const R: usize, const C: usize> Entries<T> for Mat<T, R, C> {
+    fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    fn entry(&mut self, i: usize, j: usize) -> &mut T {
+        &mut self.data[i][j]
+    }
+}
+
+impl<T> Entries<T> for DMat<T> {
+    fn shape(&self) -> (usize, usize) {
+        DMat::shape(self)
+    }
+
+    fn entry(&mut self, i: usize, j: usize) -> &mut T {
+        &mut self[(i, j)]
+    }
+}
+
+/// `A ← H A` for the reflection `I - beta v vᵀ` acting on rows `start..`.
+pub(crate) fn reflect_rows<T, A>(a: &mut A, v: &[T], beta: T, start: usize)
+where
+    T: Real,
+    A: Entries<T>,
+{
+    for j in 0..a.shape().1 {
+        let w = v.iter().enumerate().fold(T::zero(), |acc, (i, &vi)| acc + vi * *a.entry(start + i, j)) * beta;
+        for (i, &vi) in v.iter().enumerate() {
+            let e = a.entry(start + i, j);
+            *e = *e - w * vi;
+        }
+    }
+}
+
+/// `A ← A H` for the reflection `I - beta v vᵀ` acting on columns `start..`.
+pub(crate) fn reflect_cols<T, A>(a: &mut A, v: &[T], beta: T, start: usize)
+where
+    T: Real,
+    A: Entries<T>,
+{
+    for i in 0..a.shape().0 {
+        let w = v.iter().enumerate().fold(T::zero(), |acc, (j, &vj)| acc + *a.entry(i, start + j) * vj) * beta;
+        for (j, &vj) in v.iter().enumerate() {
+            let e = a.entry(i, start + j);
+            *e = *e - w * vj;
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::ops::Mul;
+
+use crate::base::matrix::Mat;
+use crate::base::num::{One, Real, Zero};
+
+/// Coefficients of the [13/13] Padé approximant of `exp`, Higham (2005).
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+/// Largest 1-norm for which the [13/13] Padé approximant reaches double precision.
+const THETA_13: f64 = 5.371920351148152;
+
+/// Iteration limit of the Denman–Beavers square root.
+const SQRT_ITERATION_MAX: usize = 100;
+
+/// Iteration limit of the inverse scaling in `logm`.
+const LOG_SQRT_MAX: i32 = 64;
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Mul<Output = T> + Zero + One + Copy,
+{
+    /// `self^n` by repeated squaring, also usable for linear recurrences over
+    /// integer matrices.
+    pub fn pow(&self, mut n: u32) -> Self {
+        let mut result = Self::identity();
+        let mut base = *self;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Real,
+{
+    /// Matrix exponential by scaling and squaring with a [13/13] Padé approximant,
+    /// `None` if an element is not finite or the Padé denominator is singular.
+    pub fn expm(&self) -> Option<Self> {
+        let identity = Self::identity();
+
+        if !self.is_finite() {
+            return None;
+        }
+        let mut squarings = 0;
+        let mut norm = self.norm_1().to_f64();
+        while norm > THETA_13 {
+            norm /= 2.0;
+            squarings += 1;
+        }
+        let a = *self / T::from_f64(2f64.powi(squarings));
+
+        let b = PADE_13.map(T::from_f64);
+        let a2 = a * a;
+        let a4 = a2 * a2;
+        let a6 = a4 * a2;
+
+        let u = a * (a6 * (a6 * b[13] + a4 * b[11] + a2 * b[9])
+            + a6 * b[7] + a4 * b[5] + a2 * b[3] + identity * b[1]);
+        let v = a6 * (a6 * b[12] + a4 * b[10] + a2 * b[8])
+            + a6 * b[6] + a4 * b[4] + a2 * b[2] + identity * b[0];
+
+        // V - U is well conditioned for ||A|| <= theta_13
+        let mut result = (v - u).solve(&(v + u))?;
+        for _ in 0..squarings {
+            result = result * result;
+        }
+        Some(result)
+    }
+
+    /// Principal square root by the Denman–Beavers iteration, `None` if the
+    /// iteration meets a singular matrix or does not converge, e.g. for a matrix
+    /// with eigenvalues on the negative real axis.
+    pub fn sqrtm(&self) -> Option<Self> {
+        let mut y = *self;
+        let mut z = Self::identity();
+        let half = T::from_f64(0.5);
+        let tolerance = T::epsilon().sqrt();
+        let mut converged = false;
+
+        for _ in 0..SQRT_ITERATION_MAX {
+            let y_inv = y.inverse()?;
+            let z_inv = z.inverse()?;
+            let next_y = (y + z_inv) * half;
+            z = (z + y_inv) * half;
+
+            let delta = (next_y - y).norm_1();
+            y = next_y;
+            if !y.is_finite() {
+                return None;
+            }
+            // the convergence is quadratic, one more step after reaching sqrt(eps)
+            // brings the error to working precision
+            if converged {
+                return Some(y);
+            }
+            converged = delta <= tolerance * y.norm_1();
+        }
+
+        None
+    }
+
+    /// Principal logarithm by inverse scaling and squaring, `None` if the matrix
+    /// has no principal logarithm that the square root iteration can reach.
+    pub fn logm(&self) -> Option<Self> {
+        let identity = Self::identity();
+        let quarter = T::from_f64(0.25);
+
+        // take square roots until A is close to I, log(A) = 2^k log(A^(1/2^k))
+        let mut a = *self;
+        let mut roots = 0;
+        while (a - identity).norm_1() > quarter {
+            if roots == LOG_SQRT_MAX {
+                return None;
+            }
+            a = a.sqrtm()?;
+            roots += 1;
+        }
+
+        // log(A) = 2 atanh(Z) = 2 (Z + Z^3/3 + Z^5/5 + ...) with Z = (A + I)^-1 (A - I)
+        let z = (a + identity).solve(&(a - identity))?;
+        let z2 = z * z;
+        let mut term = z;
+        let mut sum = z;
+        let mut k = 1;
+        loop {
+            term = term * z2;
+            k += 2;
+            let next = term / T::from_f64(k as f64);
+            sum = sum + next;
+            if next.norm_1() <= T::epsilon() * sum.norm_1() || k > 200 {
+                break;
+            }
+        }
+
+        Some(sum * T::from_f64(2f64.powi(roots + 1)))
+    }
+}
@@ -0,0 +1,10 @@
+pub mod norm;
+pub mod solve;
+pub mod triangular;
+pub mod matfunc;
+pub mod special;
+pub mod householder;
+pub mod givens;
+pub mod orthogonal;
+pub mod exact;
+pub mod qr;
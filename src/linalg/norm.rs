@@ -0,0 +1,113 @@
+use std::ops::Neg;
+
+use crate::base::matrix::Mat;
+use crate::base::num::{Real, Zero};
+
+/// Iteration limit of the power iteration in `norm_2`.
+const POWER_ITERATION_MAX: usize = 100;
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Real,
+{
+    /// Square root of the sum of the squared elements.
+    pub fn norm_frobenius(&self) -> T {
+        self.fold(T::zero(), |sum, x| sum + x * x).sqrt()
+    }
+
+    /// Maximum absolute column sum, NaN if any element is NaN.
+    pub fn norm_1(&self) -> T {
+        Mat::<T, 1, C>::from_fn(|_, j| (0..R).fold(T::zero(), |sum, i| sum + self.data[i][j].abs())).max_abs_element()
+    }
+
+    /// Maximum absolute row sum, NaN if any element is NaN.
+    pub fn norm_inf(&self) -> T {
+        Mat::<T, R, 1>::from_fn(|i, _| self.data[i].iter().fold(T::zero(), |sum, x| sum + x.abs())).max_abs_element()
+    }
+
+    /// Estimate of the spectral norm, the largest singular value, by power
+    /// iteration on `AᵀA`.
+    pub fn norm_2(&self) -> T {
+        let gram = self.transpose() * *self;
+
+        // start from the column of AᵀA with the largest norm, it is zero only for A = 0
+        let mut x = gram.col(0);
+        for j in 1..C {
+            let col = gram.col(j);
+            if col.norm_squared() > x.norm_squared() {
+                x = col;
+            }
+        }
+
+        let mut lambda = x.norm_squared().sqrt();
+        if lambda == T::zero() {
+            return lambda;
+        }
+        x = x / lambda;
+
+        let tolerance = T::epsilon().sqrt();
+        for _ in 0..POWER_ITERATION_MAX {
+            let y = gram * x;
+            let next = y.norm_squared().sqrt();
+            if next == T::zero() {
+                break;
+            }
+            x = y / next;
+            let converged = (next - lambda).abs() <= tolerance * next;
+            lambda = next;
+            if converged {
+                break;
+            }
+        }
+
+        lambda.sqrt()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.iter().all(|x| x.is_finite())
+    }
+
+    /// `AᵀA = I` within `tolerance` for every element.
+    pub fn is_orthogonal(&self, tolerance: T) -> bool {
+        let gram = self.transpose() * *self;
+        (gram - Mat::<T, C, C>::identity()).iter().all(|x| x.abs() <= tolerance)
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: PartialOrd + Neg<Output = T> + std::ops::Sub<Output = T> + Copy,
+{
+    /// `A = Aᵀ` within `tolerance` for every element.
+    pub fn is_symmetric(&self, tolerance: T) -> bool {
+        (0..N).all(|i| {
+            (0..i).all(|j| {
+                let diff = self.data[i][j] - self.data[j][i];
+                diff <= tolerance && -diff <= tolerance
+            })
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: PartialEq + Zero,
+{
+    /// All elements off the main diagonal are zero.
+    pub fn is_diagonal(&self) -> bool {
+        let zero = T::zero();
+        (0..R).all(|i| (0..C).all(|j| i == j || self.data[i][j] == zero))
+    }
+
+    /// All elements below the main diagonal are zero.
+    pub fn is_upper_triangular(&self) -> bool {
+        let zero = T::zero();
+        (0..R).all(|i| (0..C.min(i)).all(|j| self.data[i][j] == zero))
+    }
+
+    /// All elements above the main diagonal are zero.
+    pub fn is_lower_triangular(&self) -> bool {
+        let zero = T::zero();
+        (0..R).all(|i| (i + 1..C).all(|j| self.data[i][j] == zero))
+    }
+}
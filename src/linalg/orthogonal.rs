@@ -0,0 +1,45 @@
+use crate::base::matrix::Mat;
+use crate::base::num::Real;
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Real,
+{
+    /// Orthonormalize the columns by modified Gram–Schmidt and return them with
+    /// the rank. A column whose norm drops below `sqrt(eps)` of its original norm
+    /// after removing the previous directions is treated as dependent and set to zero.
+    pub fn orthonormalize_columns(&self) -> (Self, usize) {
+        let zero = T::zero();
+        let tolerance = T::epsilon().sqrt();
+        let mut q = *self;
+        let mut rank = 0;
+
+        for j in 0..C {
+            let norm = q.col(j).norm_squared().sqrt();
+            // remove the previous directions one at a time from the updated column
+            for k in 0..j {
+                let mut dot = zero;
+                for i in 0..R {
+                    dot = dot + q.data[i][k] * q.data[i][j];
+                }
+                for i in 0..R {
+                    q.data[i][j] = q.data[i][j] - dot * q.data[i][k];
+                }
+            }
+            let length = q.col(j).norm_squared().sqrt();
+
+            if length == zero || length <= tolerance * norm {
+                for i in 0..R {
+                    q.data[i][j] = zero;
+                }
+            } else {
+                for i in 0..R {
+                    q.data[i][j] = q.data[i][j] / length;
+                }
+                rank += 1;
+            }
+        }
+
+        (q, rank)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::base::dmatrix::DMat;
+use crate::base::matrix::Mat;
+use crate::base::num::Real;
+use crate::base::vector::Vector;
+use crate::linalg::householder::{reflect_cols, reflect_rows, reflector, Householder};
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Real,
+{
+    /// Householder QR, `A = Q R` with `Q` orthogonal and `R` upper triangular.
+    pub fn qr(&self) -> (Mat<T, R, R>, Self) {
+        let mut q = Mat::identity();
+        let mut r = *self;
+        for k in 0..C.min(R) {
+            let h = Householder::from_subvector(&Vector::from_fn(|i| r.data[i][k]), k);
+            h.apply_left(&mut r);
+            h.apply_right(&mut q);
+            for i in k + 1..R {
+                r.data[i][k] = T::zero();
+            }
+        }
+        (q, r)
+    }
+
+    /// Least-squares solution of `self * X = b` through the QR factorization,
+    /// minimizing `‖self * X - b‖` column by column. `None` if the columns are
+    /// numerically dependent.
+    pub fn solve_least_squares<const K: usize>(&self, b: &Mat<T, R, K>) -> Option<Mat<T, C, K>> {
+        const { assert!(R >= C, "Least squares needs at least as many rows as columns") };
+        let mut a = *self;
+        let mut x = *b;
+        for k in 0..C {
+            let h = Householder::from_subvector(&Vector::from_fn(|i| a.data[i][k]), k);
+            h.apply_left(&mut a);
+            h.apply_left(&mut x);
+        }
+
+        let upper = Mat::<T, C, C>::from_fn(|i, j| a.data[i][j]);
+        if is_rank_deficient((0..C).map(|k| upper.data[k][k]), R) {
+            return None;
+        }
+        upper.solve_upper_triangular(&Mat::from_fn(|i, j| x.data[i][j]), false)
+    }
+}
+
+impl<T> DMat<T>
+where
+    T: Real,
+{
+    /// Householder QR, `A = Q R` with `Q` orthogonal and `R` upper triangular.
+    pub fn qr(&self) -> (Self, Self) {
+        let (m, n) = self.shape();
+        let mut q = DMat::identity(m);
+        let mut r = self.clone();
+        for k in 0..n.min(m) {
+            let (v, beta) = householder_column(&r, k);
+            reflect_rows(&mut r, &v, beta, k);
+            reflect_cols(&mut q, &v, beta, k);
+            for i in k + 1..m {
+                r[(i, k)] = T::zero();
+            }
+        }
+        (q, r)
+    }
+
+    /// Least-squares solution of `self * X = b`, see `Mat::solve_least_squares`.
+    ///
+    /// Panics if `b` has a different number of rows or `self` has fewer rows
+    /// than columns.
+    pub fn solve_least_squares(&self, b: &Self) -> Option<Self> {
+        let (m, n) = self.shape();
+        assert_eq!(m, b.rows(), "Matrix dimensions do not match");
+        assert!(m >= n, "Least squares needs at least as many rows as columns");
+        let mut a = self.clone();
+        let mut x = b.clone();
+        for k in 0..n {
+            let (v, beta) = householder_column(&a, k);
+            reflect_rows(&mut a, &v, beta, k);
+            reflect_rows(&mut x, &v, beta, k);
+        }
+
+        if is_rank_deficient((0..n).map(|k| a[(k, k)]), m) {
+            return None;
+        }
+        let k = x.cols();
+        let mut solution = DMat::zeros(n, k);
+        for i in (0..n).rev() {
+            for j in 0..k {
+                let mut sum = x[(i, j)];
+                for l in i + 1..n {
+                    sum = sum - a[(i, l)] * solution[(l, j)];
+                }
+                solution[(i, j)] = sum / a[(i, i)];
+            }
+        }
+        Some(solution)
+    }
+}
+
+/// Whether a diagonal of `R` is numerically singular relative to its largest entry.
+fn is_rank_deficient<T, I>(diagonal: I, rows: usize) -> bool
+where
+    T: Real,
+    I: Iterator<Item = T> + Clone,
+{
+    let largest = diagonal.clone().fold(T::zero(), |acc, d| acc.max(d.abs()));
+    let tolerance = largest * T::epsilon() * T::from_f64(rows as f64);
+    largest == T::zero() || diagonal.into_iter().any(|d| d.abs() <= tolerance)
+}
+
+/// Reflection vector for rows `k..` of column `k`, as in `Householder::from_subvector`.
+fn householder_column<T>(a: &DMat<T>, k: usize) -> (Vec<T>, T)
+where
+    T: Real,
+{
+    let mut v: Vec<T> = (k..a.rows()).map(|i| a[(i, k)]).collect();
+    let beta = reflector(&mut v);
+    (v, beta)
+}
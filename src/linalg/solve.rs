@@ -0,0 +1,45 @@
+use crate::base::matrix::Mat;
+use crate::base::num::Real;
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Real,
+{
+    /// Solve `self * X = b` by Gaussian elimination with partial pivoting,
+    /// `None` if the matrix is singular.
+    pub fn solve<const K: usize>(&self, b: &Mat<T, N, K>) -> Option<Mat<T, N, K>> {
+        let mut a = *self;
+        let mut x = *b;
+
+        for k in 0..N {
+            let mut pivot = k;
+            for i in k + 1..N {
+                if a.data[i][k].abs() > a.data[pivot][k].abs() {
+                    pivot = i;
+                }
+            }
+            if a.data[pivot][k] == T::zero() {
+                return None;
+            }
+            a.data.swap(k, pivot);
+            x.data.swap(k, pivot);
+
+            for i in k + 1..N {
+                let factor = a.data[i][k] / a.data[k][k];
+                for j in k..N {
+                    a.data[i][j] = a.data[i][j] - factor * a.data[k][j];
+                }
+                for j in 0..K {
+                    x.data[i][j] = x.data[i][j] - factor * x.data[k][j];
+                }
+            }
+        }
+
+        a.solve_upper_triangular(&x, false)
+    }
+
+    /// `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        self.solve(&Self::identity())
+    }
+}
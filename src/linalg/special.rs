@@ -0,0 +1,239 @@
+use std::ops::{Mul, Neg};
+
+use crate::base::dmatrix::DMat;
+use crate::base::matrix::Mat;
+use crate::base::num::{One, Real, Zero};
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Copy,
+{
+    /// Toeplitz matrix, constant along each diagonal, with first column `col`
+    /// and first row `row`. `col[0]` is used for the main diagonal.
+    pub fn toeplitz(col: [T; R], row: [T; C]) -> Self {
+        Self::from_fn(|i, j| if i >= j { col[i - j] } else { row[j - i] })
+    }
+
+    /// Hankel matrix, constant along each anti-diagonal, with first column `col`
+    /// and last row `row`. `col[R - 1]` is used for the first anti-diagonal element
+    /// of the last row.
+    pub fn hankel(col: [T; R], row: [T; C]) -> Self {
+        Self::from_fn(|i, j| if i + j < R { col[i + j] } else { row[i + j + 1 - R] })
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Copy,
+{
+    /// Circulant matrix with first column `col`, every column is the previous
+    /// one shifted down by one.
+    pub fn circulant(col: [T; N]) -> Self {
+        Self::from_fn(|i, j| col[(i + N - j) % N])
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Mul<Output = T> + One + Copy,
+{
+    /// Vandermonde matrix `A[i][j] = x[i]^j`, the interpolation matrix of a
+    /// polynomial with `C` coefficients at the nodes `x`.
+    pub fn vandermonde(x: [T; R]) -> Self {
+        let mut result = Self { data: [[T::one(); C]; R] };
+        for (row, &xi) in result.data.iter_mut().zip(&x) {
+            for j in 1..C {
+                row[j] = row[j - 1] * xi;
+            }
+        }
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mat<T, R, C>
+where
+    T: Real,
+{
+    /// Hilbert matrix `A[i][j] = 1 / (i + j + 1)`, a classic ill-conditioned test matrix.
+    pub fn hilbert() -> Self {
+        Self::from_fn(|i, j| T::one() / T::from_f64((i + j + 1) as f64))
+    }
+}
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Neg<Output = T> + Zero + One + Copy,
+{
+    /// Companion matrix of the monic polynomial
+    /// `x^N + coeffs[N - 1] x^(N - 1) + ... + coeffs[0]`, its eigenvalues are
+    /// the roots of the polynomial.
+    pub fn companion(coeffs: [T; N]) -> Self {
+        let mut result = Self::default();
+        for i in 1..N {
+            result.data[i][i - 1] = T::one();
+        }
+        for (row, &c) in result.data.iter_mut().zip(&coeffs) {
+            row[N - 1] = -c;
+        }
+        result
+    }
+}
+
+impl<T> DMat<T>
+where
+    T: Clone,
+{
+    /// Toeplitz matrix with first column `col` and first row `row`, see `Mat::toeplitz`.
+    pub fn toeplitz(col: &[T], row: &[T]) -> Self {
+        Self::from_fn(col.len(), row.len(), |i, j| if i >= j { col[i - j].clone() } else { row[j - i].clone() })
+    }
+
+    /// Hankel matrix with first column `col` and last row `row`, see `Mat::hankel`.
+    pub fn hankel(col: &[T], row: &[T]) -> Self {
+        let n = col.len();
+        Self::from_fn(n, row.len(), |i, j| if i + j < n { col[i + j].clone() } else { row[i + j + 1 - n].clone() })
+    }
+
+    /// Circulant matrix with first column `col`, see `Mat::circulant`.
+    pub fn circulant(col: &[T]) -> Self {
+        let n = col.len();
+        Self::from_fn(n, n, |i, j| col[(i + n - j) % n].clone())
+    }
+}
+
+impl<T> DMat<T>
+where
+    T: Mul<Output = T> + One + Clone,
+{
+    /// Vandermonde matrix with `cols` columns, see `Mat::vandermonde`.
+    pub fn vandermonde(x: &[T], cols: usize) -> Self {
+        let mut result = Self::from_elem(x.len(), cols, T::one());
+        for i in 0..x.len() {
+            for j in 1..cols {
+                result[(i, j)] = result[(i, j - 1)].clone() * x[i].clone();
+            }
+        }
+        result
+    }
+}
+
+impl<T> DMat<T>
+where
+    T: Real,
+{
+    /// `n x n` Hilbert matrix, see `Mat::hilbert`.
+    pub fn hilbert(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| T::one() / T::from_f64((i + j + 1) as f64))
+    }
+
+    /// Companion matrix of a monic polynomial, see `Mat::companion`.
+    pub fn companion(coeffs: &[T]) -> Self {
+        let n = coeffs.len();
+        Self::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                -coeffs[i]
+            } else if i == j + 1 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+/// Solve a symmetric Toeplitz system `A x = b` with `A[i][j] = r[|i - j|]` by
+/// the Levinson–Durbin recursion in O(n²).
+///
+/// Fails if a leading principal minor is singular, which can't happen for a
+/// positive definite matrix.
+pub fn solve_toeplitz_symmetric<T>(r: &[T], b: &[T]) -> Result<Vec<T>, &'static str>
+where
+    T: Real,
+{
+    let n = r.len();
+    if n == 0 || b.len() != n {
+        return Err("Vector length does not match matrix dimensions");
+    }
+    if r[0] == T::zero() {
+        return Err("Singular leading minor");
+    }
+
+    // normalize to a unit diagonal, Golub & Van Loan algorithm 4.7.2
+    let scale = r[0];
+    let r: Vec<T> = r.iter().map(|&x| x / scale).collect();
+    let b: Vec<T> = b.iter().map(|&x| x / scale).collect();
+    let r0 = if n > 1 { r[1] } else { T::zero() };
+
+    let mut x = vec![b[0]];
+    let mut y = vec![-r0];
+    let mut alpha = -r0;
+    let mut beta = T::one();
+
+    for k in 1..n {
+        beta = (T::one() - alpha * alpha) * beta;
+        if beta == T::zero() {
+            return Err("Singular leading minor");
+        }
+
+        let mut dot = T::zero();
+        for i in 1..=k {
+            dot = dot + r[i] * x[k - i];
+        }
+        let mu = (b[k] - dot) / beta;
+        for i in 0..k {
+            x[i] = x[i] + mu * y[k - 1 - i];
+        }
+        x.push(mu);
+
+        if k + 1 < n {
+            let mut dot = T::zero();
+            for i in 1..=k {
+                dot = dot + r[i] * y[k - i];
+            }
+            alpha = (-r[k + 1] - dot) / beta;
+            let reversed: Vec<T> = y.iter().rev().copied().collect();
+            for i in 0..k {
+                y[i] = y[i] + alpha * reversed[i];
+            }
+            y.push(alpha);
+        }
+    }
+
+    Ok(x)
+}
+
+/// Solve the Vandermonde system `V a = f` with `V[i][j] = x[i]^j` by the
+/// Björck–Pereyra algorithm in O(n²), `a` are the coefficients, lowest degree
+/// first, of the polynomial interpolating the points `(x[i], f[i])`.
+///
+/// Fails if two nodes coincide.
+pub fn solve_vandermonde<T>(x: &[T], f: &[T]) -> Result<Vec<T>, &'static str>
+where
+    T: Real,
+{
+    let n = x.len();
+    if f.len() != n {
+        return Err("Vector length does not match matrix dimensions");
+    }
+
+    // Newton divided differences
+    let mut a = f.to_vec();
+    for k in 0..n.saturating_sub(1) {
+        for i in (k + 1..n).rev() {
+            let dx = x[i] - x[i - k - 1];
+            if dx == T::zero() {
+                return Err("Nodes must be distinct");
+            }
+            a[i] = (a[i] - a[i - 1]) / dx;
+        }
+    }
+
+    // Newton form to monomial coefficients
+    for k in (0..n.saturating_sub(1)).rev() {
+        for i in k..n - 1 {
+            a[i] = a[i] - x[k] * a[i + 1];
+        }
+    }
+
+    Ok(a)
+}
@@ -0,0 +1,127 @@
+use crate::base::matrix::Mat;
+use crate::base::num::Real;
+
+impl<T, const N: usize> Mat<T, N, N>
+where
+    T: Real,
+{
+    /// Solve `L * X = b` by forward substitution, reading only the lower
+    /// triangle of `self`. With `unit_diagonal` the diagonal is taken as ones
+    /// and not read. `None` if a diagonal element is zero.
+    pub fn solve_lower_triangular<const K: usize>(&self, b: &Mat<T, N, K>, unit_diagonal: bool) -> Option<Mat<T, N, K>> {
+        let mut x = *b;
+        for i in 0..N {
+            if !unit_diagonal && self.data[i][i] == T::zero() {
+                return None;
+            }
+            for j in 0..K {
+                let mut sum = x.data[i][j];
+                for k in 0..i {
+                    sum = sum - self.data[i][k] * x.data[k][j];
+                }
+                x.data[i][j] = if unit_diagonal { sum } else { sum / self.data[i][i] };
+            }
+        }
+        Some(x)
+    }
+
+    /// Solve `U * X = b` by back substitution, reading only the upper
+    /// triangle of `self`. With `unit_diagonal` the diagonal is taken as ones
+    /// and not read. `None` if a diagonal element is zero.
+    pub fn solve_upper_triangular<const K: usize>(&self, b: &Mat<T, N, K>, unit_diagonal: bool) -> Option<Mat<T, N, K>> {
+        let mut x = *b;
+        for i in (0..N).rev() {
+            if !unit_diagonal && self.data[i][i] == T::zero() {
+                return None;
+            }
+            for j in 0..K {
+                let mut sum = x.data[i][j];
+                for k in i + 1..N {
+                    sum = sum - self.data[i][k] * x.data[k][j];
+                }
+                x.data[i][j] = if unit_diagonal { sum } else { sum / self.data[i][i] };
+            }
+        }
+        Some(x)
+    }
+}
+
+/// Solve a tridiagonal system with the Thomas algorithm in O(n).
+///
+/// `lower[i]` is `A[i + 1][i]`, `diag[i]` is `A[i][i]` and `upper[i]` is
+/// `A[i][i + 1]`, so `lower` and `upper` have one element less than `diag`.
+/// No pivoting is done, which is stable for diagonally dominant systems.
+pub fn solve_tridiagonal<T>(lower: &[T], diag: &[T], upper: &[T], rhs: &[T]) -> Result<Vec<T>, &'static str>
+where
+    T: Real,
+{
+    let n = diag.len();
+    if n == 0 || lower.len() + 1 != n || upper.len() + 1 != n || rhs.len() != n {
+        return Err("Diagonal lengths do not match");
+    }
+
+    let mut c = vec![T::zero(); n];
+    let mut x = vec![T::zero(); n];
+
+    let mut pivot = diag[0];
+    if pivot == T::zero() {
+        return Err("Zero pivot");
+    }
+    x[0] = rhs[0] / pivot;
+
+    for i in 1..n {
+        c[i - 1] = upper[i - 1] / pivot;
+        pivot = diag[i] - lower[i - 1] * c[i - 1];
+        if pivot == T::zero() {
+            return Err("Zero pivot");
+        }
+        x[i] = (rhs[i] - lower[i - 1] * x[i - 1]) / pivot;
+    }
+
+    for i in (0..n - 1).rev() {
+        x[i] = x[i] - c[i] * x[i + 1];
+    }
+
+    Ok(x)
+}
+
+/// Solve a cyclic (periodic) tridiagonal system by the Sherman–Morrison formula.
+///
+/// The diagonals follow `solve_tridiagonal`, `top_right` is `A[0][n - 1]` and
+/// `bottom_left` is `A[n - 1][0]`. Needs at least 3 unknowns.
+pub fn solve_cyclic_tridiagonal<T>(lower: &[T], diag: &[T], upper: &[T], top_right: T, bottom_left: T, rhs: &[T]) -> Result<Vec<T>, &'static str>
+where
+    T: Real,
+{
+    let n = diag.len();
+    if n < 3 {
+        return Err("Cyclic system needs at least 3 unknowns");
+    }
+    if lower.len() + 1 != n || upper.len() + 1 != n || rhs.len() != n {
+        return Err("Diagonal lengths do not match");
+    }
+
+    // A = T + u vᵀ with u = (gamma, 0, .., bottom_left), v = (1, 0, .., top_right / gamma)
+    let gamma = -diag[0];
+    if gamma == T::zero() {
+        return Err("Zero pivot");
+    }
+    let mut modified = diag.to_vec();
+    modified[0] = diag[0] - gamma;
+    modified[n - 1] = diag[n - 1] - bottom_left * top_right / gamma;
+
+    let x = solve_tridiagonal(lower, &modified, upper, rhs)?;
+
+    let mut u = vec![T::zero(); n];
+    u[0] = gamma;
+    u[n - 1] = bottom_left;
+    let z = solve_tridiagonal(lower, &modified, upper, &u)?;
+
+    let denominator = T::one() + z[0] + top_right * z[n - 1] / gamma;
+    if denominator == T::zero() {
+        return Err("Zero pivot");
+    }
+    let factor = (x[0] + top_right * x[n - 1] / gamma) / denominator;
+
+    Ok(x.iter().zip(&z).map(|(&x, &z)| x - factor * z).collect())
+}
@@ -0,0 +1,264 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Neg};
+use std::fmt;
+
+use crate::base::num::{Field, One, Ring, Zero};
+use crate::number::modint::ModInt;
+
+/// Element of the prime field GF(P), `P` must be prime.
+///
+/// Unlike `ModInt` every non-zero element is invertible, so it is a `Field`
+/// and `rref`, `rank`, `nullspace` and `inverse_exact` work on `Mat<Fp<P>, R, C>`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Fp<const P: u64>(ModInt<P>);
+
+impl<const P: u64> Fp<P> {
+    #[inline]
+    pub fn new(value: u64) -> Self {
+        Self(ModInt::new(value))
+    }
+
+    #[inline]
+    pub fn value(&self) -> u64 {
+        self.0.value()
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        Self(self.0.pow(exp))
+    }
+
+    /// Panics for zero.
+    pub fn inv(&self) -> Self {
+        assert!(!self.is_zero(), "Fp division by zero");
+        // Fermat's little theorem, a^(P-2) = a^-1
+        self.pow(P - 2)
+    }
+}
+
+impl<const P: u64> From<u64> for Fp<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Fp<P> {
+    fn from(value: i64) -> Self {
+        Self(ModInt::from(value))
+    }
+}
+
+impl<const P: u64> Add for Fp<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<const P: u64> Sub for Fp<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<const P: u64> Mul for Fp<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl<const P: u64> Div for Fp<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+impl<const P: u64> Neg for Fp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Element of the binary extension field GF(2^N) modulo the irreducible
+/// polynomial `POLY` of degree `N`, bit `i` is the coefficient of `x^i`.
+///
+/// Addition is XOR, so every element is its own negative.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Gf2n<const N: u32, const POLY: u64>(u64);
+
+/// GF(2^8) with the AES polynomial `x^8 + x^4 + x^3 + x + 1`.
+pub type Gf256 = Gf2n<8, 0x11B>;
+
+impl<const N: u32, const POLY: u64> Gf2n<N, POLY> {
+    /// The polynomial with coefficients `value`, reduced modulo `POLY`.
+    pub fn new(mut value: u64) -> Self {
+        const { assert!(N >= 1 && N <= 63 && POLY >> N == 1, "POLY must have degree N") };
+        for bit in (N..64).rev() {
+            if value >> bit & 1 == 1 {
+                value ^= POLY << (bit - N);
+            }
+        }
+        Self(value)
+    }
+
+    #[inline]
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Panics for zero.
+    pub fn inv(&self) -> Self {
+        assert!(!self.is_zero(), "GF(2^n) division by zero");
+        // the multiplicative group has order 2^N - 1
+        self.pow((1u64 << N) - 2)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Add for Gf2n<N, POLY> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Sub for Gf2n<N, POLY> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Mul for Gf2n<N, POLY> {
+    type Output = Self;
+
+    /// Carry-less shift-and-add, reducing whenever the degree reaches `N`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (mut a, mut b, mut product) = (self.0, rhs.0, 0);
+        while b != 0 {
+            if b & 1 == 1 {
+                product ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if a >> N & 1 == 1 {
+                a ^= POLY;
+            }
+        }
+        Self(product)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Div for Gf2n<N, POLY> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+impl<const N: u32, const POLY: u64> Neg for Gf2n<N, POLY> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+macro_rules! impl_field {
+    ($({$($params:tt)*} $t:ty),*) => {
+        $(
+            impl<$($params)*> AddAssign for $t {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<$($params)*> SubAssign for $t {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<$($params)*> MulAssign for $t {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl<$($params)*> DivAssign for $t {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+
+            impl<$($params)*> Zero for $t {
+                #[inline]
+                fn zero() -> Self {
+                    Self::new(0)
+                }
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    self.value() == 0
+                }
+            }
+
+            impl<$($params)*> One for $t {
+                #[inline]
+                fn one() -> Self {
+                    Self::new(1)
+                }
+            }
+
+            impl<$($params)*> Ring for $t {}
+
+            impl<$($params)*> Field for $t {}
+
+            impl<$($params)*> fmt::Display for $t {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{}", self.value())
+                }
+            }
+        )*
+    };
+}
+
+impl_field!({const P: u64} Fp<P>, {const N: u32, const POLY: u64} Gf2n<N, POLY>);
+
+impl<const P: u64> fmt::Debug for Fp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value(), P)
+    }
+}
+
+impl<const N: u32, const POLY: u64> fmt::Debug for Gf2n<N, POLY> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#x} in GF(2^{})", self.0, N)
+    }
+}
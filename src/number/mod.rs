@@ -0,0 +1,4 @@
+pub mod modular;
+pub mod prime;
+pub mod modint;
+pub mod finite_field;
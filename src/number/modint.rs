@@ -0,0 +1,140 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Neg};
+use std::fmt;
+
+use crate::base::num::{One, Ring, Zero};
+use crate::number::modular::{mod_inverse, mul_mod};
+
+/// Integer modulo `M`, always reduced to `0..M`.
+///
+/// It is a `Ring` element for any `M`, so `Mat<ModInt<M>, N, N>::pow` gives
+/// modular matrix exponentiation. Use `Fp` when `M` is prime and division is needed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    #[inline]
+    pub fn new(value: u64) -> Self {
+        const { assert!(M > 0, "modulus must be positive") };
+        Self { value: value % M }
+    }
+
+    #[inline]
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut result = Self::new(1);
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse, `None` if the value is not coprime to `M`.
+    pub fn inv(&self) -> Option<Self> {
+        mod_inverse(self.value, M).map(Self::new)
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for ModInt<M> {
+    fn from(value: i64) -> Self {
+        Self::new((value as i128).rem_euclid(M as i128) as u64)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { value: ((self.value as u128 + rhs.value as u128) % M as u128) as u64 }
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { value: mul_mod(self.value, rhs.value, M) }
+    }
+}
+
+impl<const M: u64> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { value: if self.value == 0 { 0 } else { M - self.value } }
+    }
+}
+
+impl<const M: u64> AddAssign for ModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const M: u64> SubAssign for ModInt<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const M: u64> MulAssign for ModInt<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const M: u64> Zero for ModInt<M> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const M: u64> One for ModInt<M> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const M: u64> Ring for ModInt<M> {}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const M: u64> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, M)
+    }
+}
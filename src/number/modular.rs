@@ -0,0 +1,100 @@
+/// Greatest common divisor, `gcd(0, 0) = 0`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` on overflow.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// `(g, x, y)` with `a x + b y = g = gcd(a, b)` and `g >= 0`, `None` if
+/// `g = 2^63` does not fit, which happens only when `|a|` or `|b|` is `2^63`
+/// and the other is zero or also `2^63`.
+pub fn ext_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    Some((i64::try_from(old_r).ok()?, i64::try_from(old_x).ok()?, i64::try_from(old_y).ok()?))
+}
+
+/// `a b mod m` without overflow.
+#[inline]
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// `base^exp mod m` by repeated squaring, `m` must be non-zero.
+pub fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// `x` with `a x ≡ 1 (mod m)`, `None` if `a` and `m` are not coprime.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    // the Bézout coefficients of u64 operands fit in i128
+    let (mut old_r, mut r) = ((a % m) as i128, m as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+    }
+    if old_r != 1 {
+        return None;
+    }
+    Some(old_x.rem_euclid(m as i128) as u64)
+}
+
+/// Chinese remainder theorem for congruences `x ≡ r (mod m)` given as `(r, m)`,
+/// the moduli need not be coprime.
+///
+/// Returns `(x, lcm)` with the smallest non-negative solution, `None` if the
+/// congruences are inconsistent or the combined modulus overflows.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let (mut x, mut m) = (0u64, 1u64);
+    for &(r, n) in congruences {
+        if n == 0 {
+            return None;
+        }
+        let r = r % n;
+        let g = gcd(m, n);
+        let diff = (r as i128 - x as i128).rem_euclid(n as i128) as u64;
+        if !diff.is_multiple_of(g) {
+            return None;
+        }
+        // x + m t ≡ r (mod n)  ⇔  (m / g) t ≡ (r - x) / g (mod n / g)
+        let n_g = n / g;
+        let t = mul_mod(diff / g, mod_inverse((m / g) % n_g, n_g)?, n_g);
+        let next = lcm(m, n)?;
+        x = ((x as u128 + m as u128 * t as u128) % next as u128) as u64;
+        m = next;
+    }
+    Some((x, m))
+}
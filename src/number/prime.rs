@@ -0,0 +1,194 @@
+use crate::number::modular::{gcd, mul_mod, pow_mod};
+
+/// Length of one block of the segmented sieve.
+const SEGMENT_SIZE: u64 = 1 << 16;
+
+/// Witnesses that make Miller–Rabin deterministic for every `u64`.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Primes below `limit` by the sieve of Eratosthenes.
+pub fn sieve(limit: u64) -> Vec<u64> {
+    let limit = limit as usize;
+    let mut composite = vec![false; limit];
+    let mut primes = Vec::new();
+    for i in 2..limit {
+        if composite[i] {
+            continue;
+        }
+        primes.push(i as u64);
+        for j in (i * i..limit).step_by(i) {
+            composite[j] = true;
+        }
+    }
+    primes
+}
+
+/// Primes in `[low, high)` by a segmented sieve, memory is O(√high + segment)
+/// however wide the range is.
+pub fn segmented_sieve(low: u64, high: u64) -> Vec<u64> {
+    let low = low.max(2);
+    if low >= high {
+        return Vec::new();
+    }
+    let base = sieve(high.isqrt() + 1);
+    let mut primes = Vec::new();
+
+    let mut start = low;
+    while start < high {
+        let end = start.saturating_add(SEGMENT_SIZE).min(high);
+        let mut composite = vec![false; (end - start) as usize];
+        for &p in &base {
+            if p * p >= end {
+                break;
+            }
+            // first multiple of p in the segment, never p itself, none if it
+            // would lie past u64::MAX
+            let Some(first) = start.div_ceil(p).checked_mul(p) else {
+                continue;
+            };
+            let first = first.max(p * p);
+            for multiple in (first..end).step_by(p as usize) {
+                composite[(multiple - start) as usize] = true;
+            }
+        }
+        primes.extend((start..end).filter(|&n| !composite[(n - start) as usize]));
+        start = end;
+    }
+    primes
+}
+
+/// Deterministic Miller–Rabin primality test for every `u64`.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in MILLER_RABIN_BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    MILLER_RABIN_BASES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// A non-trivial factor of the composite `n` by Pollard's rho with Brent's
+/// cycle detection, `n` itself if `n` is prime or 1. Panics if `n` is zero.
+pub fn pollard_rho(n: u64) -> u64 {
+    assert!(n > 0, "Pollard rho of zero");
+    if n.is_multiple_of(2) {
+        return if n == 2 { n } else { 2 };
+    }
+    if n == 1 || is_prime(n) {
+        return n;
+    }
+
+    // batch the gcds over this many steps
+    const BATCH: u64 = 128;
+    for c in 1.. {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let (mut y, mut r, mut q) = (2u64, 1u64, 1u64);
+        let (mut x, mut ys, mut g) = (y, y, 1u64);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // the batch overshot, retrace one step at a time
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// Prime factorization as `(prime, exponent)` pairs in increasing order,
+/// empty for 0 and 1.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n > 1 {
+        collect_factors(n, &mut factors);
+    }
+    factors.sort_unstable();
+    let mut result: Vec<(u64, u32)> = Vec::new();
+    for p in factors {
+        match result.last_mut() {
+            Some((last, exp)) if *last == p => *exp += 1,
+            _ => result.push((p, 1)),
+        }
+    }
+    result
+}
+
+fn collect_factors(mut n: u64, factors: &mut Vec<u64>) {
+    // trial division strips the small primes rho is slow on
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        while n.is_multiple_of(p) {
+            factors.push(p);
+            n /= p;
+        }
+    }
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        factors.push(n);
+        return;
+    }
+    let d = pollard_rho(n);
+    collect_factors(d, factors);
+    collect_factors(n / d, factors);
+}
+
+/// Euler's totient, the count of `1..=n` coprime to `n`.
+pub fn totient(n: u64) -> u64 {
+    factorize(n).into_iter().fold(n, |phi, (p, _)| phi / p * (p - 1))
+}
+
+/// Möbius function of `n > 0`, 0 if `n` has a squared prime factor, otherwise
+/// `(-1)^k` for `k` prime factors. Panics if `n` is zero.
+pub fn mobius(n: u64) -> i8 {
+    assert!(n > 0, "Möbius function of zero");
+    let factors = factorize(n);
+    if factors.iter().any(|&(_, exp)| exp > 1) {
+        0
+    } else if factors.len().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
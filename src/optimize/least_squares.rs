@@ -0,0 +1,332 @@
+use crate::base::dmatrix::DMat;
+use crate::base::matrix::Mat;
+use crate::base::num::Real;
+use crate::base::vector::Vector;
+
+/// Stopping criteria of `levenberg_marquardt` and `gauss_newton`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LeastSquaresOptions<T> {
+    pub max_iterations: usize,
+    /// Stop when `‖Jᵀr‖∞` drops to this.
+    pub gradient_tolerance: T,
+    /// Stop when `‖δ‖ ≤ tol (‖x‖ + tol)`.
+    pub step_tolerance: T,
+    /// Stop when an accepted step lowers the cost by at most this fraction.
+    pub cost_tolerance: T,
+    /// Initial damping relative to the largest diagonal element of `JᵀJ`.
+    pub initial_damping: T,
+}
+
+impl<T> Default for LeastSquaresOptions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            max_iterations: 200,
+            gradient_tolerance: T::from_f64(1e-12),
+            step_tolerance: T::from_f64(1e-12),
+            cost_tolerance: T::from_f64(1e-15),
+            initial_damping: T::from_f64(1e-3),
+        }
+    }
+}
+
+/// Why the iteration stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    GradientTolerance,
+    StepTolerance,
+    CostTolerance,
+    MaxIterations,
+    /// The undamped normal equations of `gauss_newton` had no solution.
+    Singular,
+    /// The cost at the start or at a `gauss_newton` step, or a step itself,
+    /// was not finite. `x` is the last finite point.
+    NonFinite,
+}
+
+/// Result of a nonlinear least-squares solve, `cost` is `½‖r(x)‖²`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeastSquaresReport<X, T> {
+    pub x: X,
+    pub cost: T,
+    pub iterations: usize,
+    pub termination: Termination,
+}
+
+/// Minimize `½‖r(x)‖²` by Levenberg–Marquardt from `x0`, with the Jacobian
+/// `J[i][j] = ∂rᵢ/∂xⱼ` given by `jacobian`.
+///
+/// The damped normal equations `(JᵀJ + μI) δ = -Jᵀr` are solved by `Mat::solve`
+/// and `μ` is updated from the gain ratio as in Nielsen (1999). The Jacobian
+/// can be `finite_difference_jacobian` or `autodiff::forward::jacobian`.
+pub fn levenberg_marquardt<T, F, J, const M: usize, const N: usize>(residual: F, jacobian: J, x0: &Vector<T, N>, options: &LeastSquaresOptions<T>) -> LeastSquaresReport<Vector<T, N>, T>
+where
+    T: Real,
+    F: Fn(&Vector<T, N>) -> Vector<T, M>,
+    J: Fn(&Vector<T, N>) -> Mat<T, M, N>,
+{
+    let mut model = FixedModel { residual, jacobian, j: Mat::zeros(), r: Vector::from_fn(|_| T::zero()) };
+    minimize(&mut model, x0.data.to_vec(), options, true).map(|x| Vector::from_fn(|i| x[i]))
+}
+
+/// Minimize `½‖r(x)‖²` by undamped Gauss–Newton steps, see `levenberg_marquardt`.
+/// Fast near a small-residual solution but without any safeguard far from it.
+pub fn gauss_newton<T, F, J, const M: usize, const N: usize>(residual: F, jacobian: J, x0: &Vector<T, N>, options: &LeastSquaresOptions<T>) -> LeastSquaresReport<Vector<T, N>, T>
+where
+    T: Real,
+    F: Fn(&Vector<T, N>) -> Vector<T, M>,
+    J: Fn(&Vector<T, N>) -> Mat<T, M, N>,
+{
+    let mut model = FixedModel { residual, jacobian, j: Mat::zeros(), r: Vector::from_fn(|_| T::zero()) };
+    minimize(&mut model, x0.data.to_vec(), options, false).map(|x| Vector::from_fn(|i| x[i]))
+}
+
+/// `levenberg_marquardt` for a runtime number of residuals and parameters.
+///
+/// The damped step solves the equivalent least-squares problem
+/// `[J; √μ I] δ ≈ [-r; 0]` by `DMat::solve_least_squares`, which avoids
+/// squaring the condition number of `J`. Panics if the Jacobian shape does
+/// not match the residual and the parameters.
+pub fn levenberg_marquardt_dmat<T, F, J>(residual: F, jacobian: J, x0: &[T], options: &LeastSquaresOptions<T>) -> LeastSquaresReport<Vec<T>, T>
+where
+    T: Real,
+    F: Fn(&[T]) -> Vec<T>,
+    J: Fn(&[T]) -> DMat<T>,
+{
+    let mut model = DynModel { residual, jacobian, j: DMat::zeros(0, 0), r: Vec::new() };
+    minimize(&mut model, x0.to_vec(), options, true)
+}
+
+/// `gauss_newton` for a runtime number of residuals and parameters.
+pub fn gauss_newton_dmat<T, F, J>(residual: F, jacobian: J, x0: &[T], options: &LeastSquaresOptions<T>) -> LeastSquaresReport<Vec<T>, T>
+where
+    T: Real,
+    F: Fn(&[T]) -> Vec<T>,
+    J: Fn(&[T]) -> DMat<T>,
+{
+    let mut model = DynModel { residual, jacobian, j: DMat::zeros(0, 0), r: Vec::new() };
+    minimize(&mut model, x0.to_vec(), options, false)
+}
+
+/// Jacobian of `residual` at `x` by central differences with the step
+/// `eps^(1/3) max(1, |xⱼ|)`.
+pub fn finite_difference_jacobian<T, F, const M: usize, const N: usize>(residual: F, x: &Vector<T, N>) -> Mat<T, M, N>
+where
+    T: Real,
+    F: Fn(&Vector<T, N>) -> Vector<T, M>,
+{
+    let mut j = Mat::zeros();
+    for col in 0..N {
+        let h = difference_step(x[col]);
+        let (mut plus, mut minus) = (*x, *x);
+        plus.data[col] = x[col] + h;
+        minus.data[col] = x[col] - h;
+        let (rp, rm) = (residual(&plus), residual(&minus));
+        for row in 0..M {
+            j.data[row][col] = (rp[row] - rm[row]) / (h + h);
+        }
+    }
+    j
+}
+
+/// `finite_difference_jacobian` for a runtime number of residuals and parameters.
+pub fn finite_difference_jacobian_dmat<T, F>(residual: F, x: &[T]) -> DMat<T>
+where
+    T: Real,
+    F: Fn(&[T]) -> Vec<T>,
+{
+    let columns: Vec<Vec<T>> = (0..x.len())
+        .map(|col| {
+            let h = difference_step(x[col]);
+            let (mut plus, mut minus) = (x.to_vec(), x.to_vec());
+            plus[col] = x[col] + h;
+            minus[col] = x[col] - h;
+            let (rp, rm) = (residual(&plus), residual(&minus));
+            rp.iter().zip(&rm).map(|(&p, &m)| (p - m) / (h + h)).collect()
+        })
+        .collect();
+    let rows = columns.first().map_or(0, |c| c.len());
+    DMat::from_fn(rows, x.len(), |i, j| columns[j][i])
+}
+
+fn difference_step<T>(x: T) -> T
+where
+    T: Real,
+{
+    T::epsilon().powf(T::from_f64(1.0 / 3.0)) * x.abs().max(T::one())
+}
+
+impl<X, T> LeastSquaresReport<X, T> {
+    fn map<Y, F>(self, f: F) -> LeastSquaresReport<Y, T>
+    where
+        F: FnOnce(X) -> Y,
+    {
+        LeastSquaresReport { x: f(self.x), cost: self.cost, iterations: self.iterations, termination: self.termination }
+    }
+}
+
+/// The linear algebra of one least-squares problem, linearized at the last
+/// accepted point.
+trait Model<T> {
+    /// `½‖r(x)‖²`.
+    fn cost(&self, x: &[T]) -> T;
+
+    /// Evaluate `r` and `J` at `x` and return the gradient `Jᵀr`.
+    fn linearize(&mut self, x: &[T]) -> Vec<T>;
+
+    /// Largest diagonal element of `JᵀJ`.
+    fn max_diagonal(&self) -> T;
+
+    /// Solution of `(JᵀJ + μI) δ = -Jᵀr`.
+    fn step(&self, mu: T) -> Option<Vec<T>>;
+}
+
+struct FixedModel<F, J, T, const M: usize, const N: usize> {
+    residual: F,
+    jacobian: J,
+    j: Mat<T, M, N>,
+    r: Vector<T, M>,
+}
+
+impl<F, J, T, const M: usize, const N: usize> Model<T> for FixedModel<F, J, T, M, N>
+where
+    T: Real,
+    F: Fn(&Vector<T, N>) -> Vector<T, M>,
+    J: Fn(&Vector<T, N>) -> Mat<T, M, N>,
+{
+    fn cost(&self, x: &[T]) -> T {
+        let r = (self.residual)(&Vector::from_fn(|i| x[i]));
+        r.dot(&r) / (T::one() + T::one())
+    }
+
+    fn linearize(&mut self, x: &[T]) -> Vec<T> {
+        let x = Vector::from_fn(|i| x[i]);
+        self.r = (self.residual)(&x);
+        self.j = (self.jacobian)(&x);
+        (self.r * self.j).data.to_vec()
+    }
+
+    fn max_diagonal(&self) -> T {
+        (0..N).map(|k| self.j.col(k).norm_squared()).fold(T::zero(), T::max)
+    }
+
+    fn step(&self, mu: T) -> Option<Vec<T>> {
+        let a = self.j.transpose() * self.j + Mat::identity() * mu;
+        let g = Mat::from(self.r * self.j);
+        Some(a.solve(&-g)?.col(0).data.to_vec())
+    }
+}
+
+struct DynModel<F, J, T> {
+    residual: F,
+    jacobian: J,
+    j: DMat<T>,
+    r: Vec<T>,
+}
+
+impl<F, J, T> Model<T> for DynModel<F, J, T>
+where
+    T: Real,
+    F: Fn(&[T]) -> Vec<T>,
+    J: Fn(&[T]) -> DMat<T>,
+{
+    fn cost(&self, x: &[T]) -> T {
+        (self.residual)(x).iter().fold(T::zero(), |acc, &r| acc + r * r) / (T::one() + T::one())
+    }
+
+    fn linearize(&mut self, x: &[T]) -> Vec<T> {
+        self.r = (self.residual)(x);
+        self.j = (self.jacobian)(x);
+        assert_eq!(self.j.shape(), (self.r.len(), x.len()), "Jacobian shape does not match");
+        (0..self.j.cols())
+            .map(|k| (0..self.j.rows()).fold(T::zero(), |acc, i| acc + self.j[(i, k)] * self.r[i]))
+            .collect()
+    }
+
+    fn max_diagonal(&self) -> T {
+        (0..self.j.cols())
+            .map(|k| (0..self.j.rows()).fold(T::zero(), |acc, i| acc + self.j[(i, k)] * self.j[(i, k)]))
+            .fold(T::zero(), T::max)
+    }
+
+    fn step(&self, mu: T) -> Option<Vec<T>> {
+        let (m, n) = self.j.shape();
+        let rhs = DMat::from_fn(m, 1, |i, _| -self.r[i]);
+        let delta = if mu == T::zero() {
+            if m < n {
+                return None;
+            }
+            self.j.solve_least_squares(&rhs)?
+        } else {
+            let damping = DMat::identity(n) * mu.sqrt();
+            let a = self.j.vstack(&damping).ok()?;
+            a.solve_least_squares(&rhs.vstack(&DMat::zeros(n, 1)).ok()?)?
+        };
+        Some(delta.into_vec())
+    }
+}
+
+fn minimize<T, P>(model: &mut P, mut x: Vec<T>, options: &LeastSquaresOptions<T>, damped: bool) -> LeastSquaresReport<Vec<T>, T>
+where
+    T: Real,
+    P: Model<T>,
+{
+    let two = T::one() + T::one();
+    let norm = |v: &[T]| v.iter().fold(T::zero(), |acc, &e| acc + e * e).sqrt();
+
+    let mut cost = model.cost(&x);
+    if !cost.is_finite() {
+        return LeastSquaresReport { x, cost, iterations: 0, termination: Termination::NonFinite };
+    }
+    let mut g = model.linearize(&x);
+    let mut mu = if damped { options.initial_damping * model.max_diagonal() } else { T::zero() };
+    let mut nu = two;
+
+    let report = |x, cost, iterations, termination| LeastSquaresReport { x, cost, iterations, termination };
+    for iteration in 0..options.max_iterations {
+        if g.iter().all(|e| e.abs() <= options.gradient_tolerance) {
+            return report(x, cost, iteration, Termination::GradientTolerance);
+        }
+        let Some(step) = model.step(mu) else {
+            return report(x, cost, iteration, Termination::Singular);
+        };
+        if !step.iter().all(|d| d.is_finite()) {
+            return report(x, cost, iteration, Termination::NonFinite);
+        }
+        if norm(&step) <= options.step_tolerance * (norm(&x) + options.step_tolerance) {
+            return report(x, cost, iteration, Termination::StepTolerance);
+        }
+
+        let candidate: Vec<T> = x.iter().zip(&step).map(|(&x, &d)| x + d).collect();
+        let new_cost = model.cost(&candidate);
+        if damped {
+            // gain ratio of the actual to the reduction predicted by the linear
+            // model, a non-finite cost gives a NaN ratio and the step is rejected
+            let predicted = step.iter().zip(&g).fold(T::zero(), |acc, (&d, &g)| acc + d * (mu * d - g)) / two;
+            let rho = (cost - new_cost) / predicted;
+            if !(predicted > T::zero() && rho > T::zero()) {
+                mu = mu * nu;
+                nu = nu * two;
+                continue;
+            }
+            let t = two * rho - T::one();
+            mu = mu * (T::one() - t * t * t).max(T::one() / T::from_f64(3.0));
+            nu = two;
+        } else if !new_cost.is_finite() {
+            return report(x, cost, iteration, Termination::NonFinite);
+        }
+
+        let decrease = cost - new_cost;
+        let previous = cost;
+        x = candidate;
+        cost = new_cost;
+        g = model.linearize(&x);
+        if decrease.abs() <= options.cost_tolerance * previous {
+            return report(x, cost, iteration + 1, Termination::CostTolerance);
+        }
+    }
+
+    report(x, cost, options.max_iterations, Termination::MaxIterations)
+}
@@ -0,0 +1 @@
+pub mod least_squares;
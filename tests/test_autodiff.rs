@@ -0,0 +1,297 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_dual_arithmetic() {
+        use gk_math::autodiff::forward::{derivative, Dual};
+
+        let x = Dual::variable(2.0f64, 0);
+        let c = Dual::constant(3.0);
+        assert_eq!((x * x + c).deriv(), 4.0);
+        assert_eq!((c / x).deriv(), -0.75);
+        assert_eq!((x - c * x).deriv(), -2.0);
+        assert_eq!((x * 5.0 / 2.0).deriv(), 2.5);
+        assert_eq!(x.powi(3).deriv(), 12.0);
+        assert_eq!(x.powi(0), Dual::constant(1.0));
+        assert_eq!(format!("{}", x * x), "4 + 4ε");
+
+        // compared against the hand-written derivatives
+        let points = [-2.5f64, -0.3, 0.7, 1.9];
+        for &t in &points {
+            let d = derivative(|x| x.sin() * x.exp(), t);
+            assert!((d - t.exp() * (t.sin() + t.cos())).abs() < 1e-13);
+            let d = derivative(|x| (x * x + Dual::constant(1.0)).sqrt(), t);
+            assert!((d - t / (t * t + 1.0f64).sqrt()).abs() < 1e-15);
+            let d = derivative(|x| x.cos().tan(), t);
+            assert!((d + t.sin() / t.cos().cos().powi(2)).abs() < 1e-13);
+            let d = derivative(|x| x.abs().ln(), t);
+            assert!((d - 1.0 / t).abs() < 1e-14);
+            // x^x = exp(x ln x), the exponent carries a gradient too
+            let d = derivative(|x| x.abs().powf(x), t);
+            let expected = t.abs().powf(t) * (t.abs().ln() + 1.0);
+            assert!((d - expected).abs() < 1e-13);
+        }
+        // a constant exponent works for a negative base
+        assert_eq!(derivative(|x| x.powf(Dual::constant(2.0)), -3.0), -6.0);
+    }
+
+    #[test]
+    fn test_dual_ordering() {
+        use gk_math::autodiff::forward::Dual;
+        use gk_math::base::num::{Real, Zero};
+
+        let a = Dual::new(1.0f64, [5.0]);
+        let b = Dual::new(2.0, [-5.0]);
+        assert!(a < b && a <= b);
+        assert_eq!(Real::max(a, b), b);
+        assert_eq!(a.partial_cmp(&Dual::new(1.0, [0.0])), None);
+        assert!(!Dual::new(0.0f64, [1.0]).is_zero());
+        assert!(Real::is_finite(a) && !Real::is_finite(a / Dual::zero()));
+    }
+
+    #[test]
+    fn test_gradient_and_jacobian() {
+        use gk_math::autodiff::forward::{gradient, jacobian, value_and_jacobian, DualN};
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+
+        // Rosenbrock
+        let rosenbrock = |v: &Vector<DualN<f64, 2>, 2>| {
+            let one = DualN::constant(1.0);
+            let a = one - v[0];
+            let b = v[1] - v[0] * v[0];
+            a * a + b * b * 100.0
+        };
+        let g = gradient(rosenbrock, &Vector::new([-1.2, 1.0]));
+        assert!((g[0] + 215.6).abs() < 1e-12);
+        assert!((g[1] + 88.0).abs() < 1e-12);
+        assert_eq!(gradient(rosenbrock, &Vector::new([1.0, 1.0])).data, [0.0, 0.0]);
+
+        // polar to cartesian
+        let polar = |v: &Vector<DualN<f64, 2>, 2>| Vector::new([v[0] * v[1].cos(), v[0] * v[1].sin()]);
+        let (r, theta) = (2.0f64, 0.6f64);
+        let (y, j) = value_and_jacobian(polar, &Vector::new([r, theta]));
+        assert!((y[0] - r * theta.cos()).abs() < 1e-15);
+        let expected = Mat::new([[theta.cos(), -r * theta.sin()], [theta.sin(), r * theta.cos()]]);
+        assert!((j - expected).max_abs_element() < 1e-15);
+
+        // non-square, 3 outputs of 2 inputs
+        let j = jacobian(|v: &Vector<DualN<f64, 2>, 2>| Vector::new([v[0] * v[1], v[0].exp(), v[1] / v[0]]), &Vector::new([1.0, 2.0]));
+        let expected = Mat::new([[2.0, 1.0], [1.0f64.exp(), 0.0], [-2.0, 1.0]]);
+        assert!((j - expected).max_abs_element() < 1e-15);
+    }
+
+    #[test]
+    fn test_newton_with_jacobian() {
+        use gk_math::autodiff::forward::{value_and_jacobian, DualN};
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+
+        // intersection of the unit circle and the parabola y = x^2
+        let f = |v: &Vector<DualN<f64, 2>, 2>| {
+            let one = DualN::constant(1.0);
+            Vector::new([v[0] * v[0] + v[1] * v[1] - one, v[1] - v[0] * v[0]])
+        };
+        let mut x = Vector::new([1.0, 1.0]);
+        for _ in 0..20 {
+            let (y, j) = value_and_jacobian(f, &x);
+            let step = j.solve(&Mat::from(y)).unwrap();
+            x -= Vector::from(step);
+        }
+        let y = (5.0f64.sqrt() - 1.0) / 2.0;
+        assert!((x[1] - y).abs() < 1e-15);
+        assert!((x[0] - y.sqrt()).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_dual_in_vector_math() {
+        use gk_math::autodiff::forward::{Dual, DualN};
+        use macro_utils::VectorMath;
+
+        #[derive(Clone, Copy, VectorMath)]
+        struct Vec3<T> {
+            x: T,
+            y: T,
+            z: T,
+        }
+
+        // d/dt of |p + t d| at t = 0 is the direction along p
+        let t = Dual::variable(0.0f32, 0);
+        let p = Vec3::new(Dual::constant(1.0), Dual::constant(2.0), Dual::constant(2.0));
+        let d = Vec3::new(Dual::constant(3.0), Dual::constant(0.0), Dual::constant(-1.0));
+        let length = (p + d * t).length();
+        assert_eq!(length.value, 3.0);
+        assert!((length.deriv() - 1.0 / 3.0).abs() < 1e-7);
+
+        // the cross product is bilinear, its gradient is a cross product
+        let [a, b] = DualN::variables([2.0f64, -1.0]);
+        let u = Vec3::new(a, b, DualN::constant(0.0));
+        let v = Vec3::new(DualN::constant(0.0), a, b);
+        let w = Vec3::cross(&u, &v);
+        assert_eq!(w.x.grad, [0.0, -2.0]);
+        assert_eq!(w.z.grad, [2.0 * 2.0, 0.0]);
+        assert!((w.normalized().length().value - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_dual_in_mat() {
+        use gk_math::autodiff::forward::Dual;
+        use gk_math::base::matrix::Mat;
+
+        // A(t) = A + t B, d/dt A⁻¹ = -A⁻¹ B A⁻¹
+        let a = Mat::new([[4.0f64, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        let b = Mat::new([[0.0f64, 1.0, 2.0], [-1.0, 0.0, 1.0], [3.0, 0.0, 1.0]]);
+        let at: Mat<Dual<f64>, 3, 3> = Mat::from_fn(|i, j| Dual::new(a.data[i][j], [b.data[i][j]]));
+        let inv = at.inverse().unwrap();
+        let a_inv = a.inverse().unwrap();
+        let expected = -(a_inv * b * a_inv);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((inv.data[i][j].value - a_inv.data[i][j]).abs() < 1e-15);
+                assert!((inv.data[i][j].deriv() - expected.data[i][j]).abs() < 1e-14);
+            }
+        }
+
+        // d/dt ‖A + tB‖_F = <A, B> / ‖A‖_F
+        let norm = at.norm_frobenius();
+        let dot: f64 = (0..3).flat_map(|i| (0..3).map(move |j| (i, j))).map(|(i, j)| a.data[i][j] * b.data[i][j]).sum();
+        assert!((norm.deriv() - dot / a.norm_frobenius()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_var_arithmetic() {
+        use gk_math::autodiff::reverse::{Tape, Var};
+        use gk_math::base::num::Zero;
+
+        let tape = Tape::new();
+        let x = tape.var(2.0f64);
+        let y = tape.var(-3.0);
+        let c = Var::constant(0.5);
+        let z = x * y + (x / y) * c - x.sin() * y.exp() + x.powi(3) - y.abs().sqrt();
+        let g = z.gradient();
+        let expected_x = -3.0 + 0.5 / -3.0 - 2.0f64.cos() * (-3.0f64).exp() + 12.0;
+        let expected_y = 2.0 - 0.5 * 2.0 / 9.0 - 2.0f64.sin() * (-3.0f64).exp() + 0.5 / 3.0f64.sqrt();
+        assert!((g.wrt(&x) - expected_x).abs() < 1e-14);
+        assert!((g.wrt(&y) - expected_y).abs() < 1e-14);
+        assert_eq!(g.wrt(&c), 0.0);
+
+        // a variable used many times accumulates every path
+        let mut sum = Var::constant(0.0);
+        for _ in 0..10 {
+            sum += x * x;
+        }
+        assert_eq!(sum.gradient().wrt(&x), 40.0);
+        // a var recorded after the output has no influence on it
+        let later = tape.var(1.0);
+        assert_eq!(sum.gradient().wrt(&later), 0.0);
+
+        // a recorded zero is not skipped as a structural zero
+        let zero = tape.var(0.0);
+        assert!(!zero.is_zero() && Var::<f64>::zero().is_zero());
+        assert!(!(x - x).is_zero());
+
+        // atan2 and powf with a recorded exponent
+        let a = y.atan2(x);
+        let g = a.gradient();
+        assert!((g.wrt(&y) - 2.0 / 13.0).abs() < 1e-15);
+        assert!((g.wrt(&x) - 3.0 / 13.0).abs() < 1e-15);
+        let p = x.powf(x);
+        assert!((p.gradient().wrt(&x) - 4.0 * (2.0f64.ln() + 1.0)).abs() < 1e-14);
+        assert_eq!(y.powf(Var::constant(2.0)).gradient().wrt(&y), -6.0);
+
+        assert!(Var::<f64>::constant(1.0).gradient().wrt(&x) == 0.0);
+        assert_eq!(format!("{:?}", x), "Var(2.0 @ 0)");
+        assert_eq!(format!("{:?}", c), "Var(0.5)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Vars from different tapes")]
+    fn test_var_different_tapes() {
+        use gk_math::autodiff::reverse::Tape;
+
+        let (a, b) = (Tape::new(), Tape::new());
+        let _ = a.var(1.0f64) + b.var(2.0);
+    }
+
+    #[test]
+    fn test_tape_reuse() {
+        use gk_math::autodiff::reverse::Tape;
+
+        let mut tape = Tape::new();
+        {
+            let x = tape.var(1.0f64);
+            let _ = x * x + x;
+        }
+        assert_eq!(tape.len(), 3);
+        tape.clear();
+        assert!(tape.is_empty());
+        let x = tape.var(3.0);
+        assert_eq!((x * x).gradient().wrt(&x), 6.0);
+    }
+
+    #[test]
+    fn test_gradient_check_many_variables() {
+        use gk_math::autodiff::reverse::gradient;
+        use gk_math::base::num::Real;
+
+        // written once for f64 and for Var
+        fn cost<T: Real>(x: &[T]) -> T {
+            let half = T::from_f64(0.5);
+            let mut total = T::zero();
+            for i in 0..x.len() {
+                let next = x[(i + 1) % x.len()];
+                total = total + x[i].sin() * next + (-(x[i] * x[i]) * half).exp();
+                total = total + (T::one() + x[i] * x[i]).ln() + (x[i] * x[i] + next * next + T::one()).sqrt();
+                total = total + x[i].atan2(next + T::from_f64(3.0)) + (x[i].abs() + T::one()).powf(next * half);
+            }
+            total
+        }
+
+        let n = 200;
+        let x: Vec<f64> = (0..n).map(|i| ((i * 37 % 101) as f64 / 101.0 - 0.5) * 3.0).collect();
+        let (value, grad) = gradient(|v| cost(v), &x);
+        assert!((value - cost(&x)).abs() < 1e-12 * value.abs());
+
+        // central differences, O(h²) truncation plus O(eps / h) rounding
+        let h = 1e-5;
+        for i in 0..n {
+            let (mut plus, mut minus) = (x.clone(), x.clone());
+            plus[i] += h;
+            minus[i] -= h;
+            let fd = (cost(&plus) - cost(&minus)) / (2.0 * h);
+            assert!((grad[i] - fd).abs() < 1e-6 * grad[i].abs().max(1.0), "{}: {} {}", i, grad[i], fd);
+        }
+    }
+
+    #[test]
+    fn test_var_in_mat() {
+        use gk_math::autodiff::reverse::{Tape, Var};
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[1.0f64, 2.0, 0.0], [0.0, 1.0, -1.0], [3.0, 0.0, 1.0], [1.0, 1.0, 1.0]]);
+        let b = Mat::new([[1.0f64], [0.0], [2.0], [-1.0]]);
+        let x0 = Mat::new([[0.5f64], [-0.25], [1.5]]);
+
+        let tape = Tape::new();
+        let x = x0.map(|v| tape.var(v));
+        let cost = (a.map(Var::constant) * x - b.map(Var::constant)).norm_frobenius();
+        let g = cost.gradient();
+
+        // ∇‖Ax - b‖ = Aᵀ(Ax - b) / ‖Ax - b‖
+        let r = a * x0 - b;
+        assert!((cost.value() - r.norm_frobenius()).abs() < 1e-15);
+        let expected = a.transpose() * r / r.norm_frobenius();
+        for i in 0..3 {
+            assert!((g.wrt(&x.data[i][0]) - expected.data[i][0]).abs() < 1e-15);
+        }
+
+        // and by finite differences
+        let h = 1e-6;
+        for i in 0..3 {
+            let (mut plus, mut minus) = (x0, x0);
+            plus.data[i][0] += h;
+            minus.data[i][0] -= h;
+            let fd = ((a * plus - b).norm_frobenius() - (a * minus - b).norm_frobenius()) / (2.0 * h);
+            assert!((g.wrt(&x.data[i][0]) - fd).abs() < 1e-8);
+        }
+    }
+}
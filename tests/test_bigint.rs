@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_bigint_matches_i128() {
+        use gk_math::base::bigint::BigInt;
+
+        // pseudo-random operands covering every sign combination
+        let mut seed = 0x2545F4914F6CDD1Du64;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed as i64 >> (seed % 40)) as i128
+        };
+        for _ in 0..500 {
+            let (a, b) = (next(), next());
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(&x + &y, BigInt::from(a + b));
+            assert_eq!(&x - &y, BigInt::from(a - b));
+            assert_eq!(&x * &y, BigInt::from(a * b));
+            if b != 0 {
+                assert_eq!(x.div_rem(&y), (BigInt::from(a / b), BigInt::from(a % b)));
+            }
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            assert_eq!(x.to_string(), a.to_string());
+        }
+    }
+
+    #[test]
+    fn test_bigint_large() {
+        use gk_math::base::bigint::BigInt;
+
+        let two = BigInt::from(2);
+        assert_eq!(two.pow(100).to_string(), "1267650600228229401496703205376");
+        assert_eq!(two.pow(100).bits(), 101);
+        let factorial = (1..=30).fold(BigInt::from(1), |acc, i| acc * BigInt::from(i));
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+
+        // operands far above the Karatsuba threshold
+        let a = BigInt::from(3).pow(4000) - BigInt::from(1);
+        let b = BigInt::from(7).pow(2500) + BigInt::from(12345);
+        let product = &a * &b;
+        assert_eq!(product.div_rem(&b), (a.clone(), BigInt::from(0)));
+        assert_eq!(&product / &a, b);
+        let ten = BigInt::from(10);
+        assert_eq!(ten.pow(3000) * ten.pow(2000), ten.pow(5000));
+        assert_eq!(ten.pow(5000).to_string().len(), 5001);
+
+        // Knuth D with remainders that need the add-back step
+        let n = BigInt::from(u64::MAX).pow(5) + BigInt::from(7);
+        let d = BigInt::from(u64::MAX).pow(2) - BigInt::from(1);
+        let (q, r) = n.div_rem(&d);
+        assert_eq!(&q * &d + &r, n);
+        assert!(r < d && !r.is_negative());
+        let (q, r) = (-&n).div_rem(&d);
+        assert_eq!(&q * &d + &r, -&n);
+        assert!(r.is_negative());
+
+        assert_eq!(BigInt::from(12).gcd(&BigInt::from(-18)), BigInt::from(6));
+        assert_eq!(a.gcd(&(&a * &BigInt::from(5))), a);
+        assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!((BigInt::from(i64::MAX) + BigInt::from(1)).to_i64(), None);
+        assert_eq!(two.pow(70).to_f64(), 2f64.powi(70));
+        // 2^17 is half an ulp of 2^70, ties go to even and the dropped bits break them
+        let (base, half) = (two.pow(70), two.pow(17));
+        assert_eq!((&base + &half).to_f64(), 2f64.powi(70));
+        assert_eq!((&base + &half + BigInt::from(1)).to_f64(), 2f64.powi(70) + 2f64.powi(18));
+        assert_eq!((&base + &half * &BigInt::from(3)).to_f64(), 2f64.powi(70) + 2f64.powi(19));
+        assert_eq!((-(&base + &half + BigInt::from(1))).to_f64(), -(2f64.powi(70) + 2f64.powi(18)));
+        assert_eq!(BigInt::from(u64::MAX).to_f64(), u64::MAX as f64);
+        assert_eq!((two.pow(1024) - BigInt::from(1)).to_f64(), f64::INFINITY);
+        assert_eq!((two.pow(1024) - two.pow(970)).to_f64(), f64::INFINITY);
+        assert_eq!((two.pow(1024) - two.pow(970) - BigInt::from(1)).to_f64(), f64::MAX);
+    }
+
+    #[test]
+    fn test_bigint_radix() {
+        use gk_math::base::bigint::BigInt;
+
+        let x: BigInt = "-123456789012345678901234567890".parse().unwrap();
+        assert_eq!(x.to_string(), "-123456789012345678901234567890");
+        assert_eq!(BigInt::from_str_radix("ff", 16), Ok(BigInt::from(255)));
+        assert_eq!(BigInt::from_str_radix("-zz", 36), Ok(BigInt::from(-1295)));
+        assert_eq!(BigInt::from(-255).to_str_radix(2), "-11111111");
+        assert_eq!(BigInt::from(0).to_str_radix(7), "0");
+        for radix in 2..=36 {
+            assert_eq!(BigInt::from_str_radix(&x.to_str_radix(radix), radix), Ok(x.clone()));
+        }
+        assert_eq!(BigInt::from(1u64 << 32).to_str_radix(10), "4294967296");
+        assert!(BigInt::from_str_radix("12a", 10).is_err());
+        assert!(BigInt::from_str_radix("-", 10).is_err());
+        assert!(BigInt::from_str_radix("1", 37).is_err());
+    }
+
+    #[test]
+    fn test_big_mat() {
+        use gk_math::base::bigint::{BigInt, BigRational};
+        use gk_math::base::matrix::Mat;
+
+        // entries of 30 digits overflow every primitive in the Bareiss minors
+        let big = BigInt::from(10).pow(30);
+        let a = Mat::<BigInt, 3, 3>::from_fn(|i, j| &big * BigInt::from((i * 3 + j) as i64 + 1) + BigInt::from((i == j) as i64));
+        // det(tM + I) = 1 + t tr(M) + t² (sum of the principal 2x2 minors) + t³ det(M)
+        let expected = BigInt::from(1) + &big * BigInt::from(15) - &big * &big * BigInt::from(18);
+        assert_eq!(a.det_bareiss(), expected);
+
+        // the exact inverse of the 8 x 8 Hilbert matrix, (H⁻¹)[0][0] = n²
+        let h = Mat::<BigRational, 8, 8>::from_fn(|i, j| BigRational::new(BigInt::from(1), BigInt::from(i + j + 1)));
+        let inverse = h.inverse_exact().unwrap();
+        assert_eq!(inverse[0][0], BigRational::from(BigInt::from(64)));
+        // (H⁻¹)[n-1][n-1] = (2n - 1) C(2n - 2, n - 1)² = 15 * 3432²
+        assert_eq!(inverse[7][7], BigRational::from(BigInt::from(176679360)));
+        assert_eq!(h.clone() * inverse, Mat::identity());
+        assert_eq!(h.rank(), 8);
+        assert_eq!(h.transpose(), h);
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_complex_arithmetic() {
+        use gk_math::base::complex::Complex;
+
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(-a, Complex::new(-1.0, -2.0));
+        assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(Complex::<f64>::i() * Complex::i(), Complex::from(-1.0));
+        assert_eq!(a.norm_sqr(), 5.0);
+
+        let mut c = a;
+        c += b;
+        c *= Complex::i();
+        assert_eq!(c, Complex::new(-1.0, 4.0));
+        c /= Complex::i();
+        c -= b;
+        assert_eq!(c, a);
+
+        assert_eq!(format!("{}", a), "1+2i");
+        assert_eq!(format!("{}", b), "3-1i");
+    }
+
+    #[test]
+    fn test_complex_div_scaling() {
+        use gk_math::base::complex::Complex;
+        use gk_math::base::rational::Rational;
+
+        // c² + d² overflows or underflows, the quotient does not
+        let big = Complex::new(1e200, 1e200);
+        assert_eq!(big / big, Complex::new(1.0, 0.0));
+        let small = Complex::new(1e-200, 1e-200);
+        assert_eq!(small / small, Complex::new(1.0, 0.0));
+        assert_eq!(Complex::new(1e300, 0.0) / Complex::new(0.0, 1e300), Complex::new(0.0, -1.0));
+        let q = Complex::new(3e200, 4e200) / Complex::new(1e160, -2e160);
+        assert!((q - Complex::new(-1e40, 2e40)).abs() < 1e26);
+        let q = Complex::new(3e-200, 4e-200) / Complex::new(1e-160, -2e-160);
+        assert!((q - Complex::new(-1e-40, 2e-40)).abs() < 1e-54);
+        assert!((Complex::new(1.0f64, 0.0) / Complex::new(0.0, 0.0)).re.is_nan());
+
+        // exact fields keep the textbook formula
+        let r = |n, d| Rational::new(n, d);
+        let a = Complex::new(r(1, 2), r(-1, 3));
+        let b = Complex::new(r(2, 5), r(3, 7));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(a / b, a * (Complex::new(r(1, 1), r(0, 1)) / b));
+    }
+
+    #[test]
+    fn test_complex_functions() {
+        use gk_math::base::complex::Complex;
+        use std::f64::consts::PI;
+
+        let close = |a: Complex<f64>, b: Complex<f64>| (a - b).abs() < 1e-14;
+
+        let z = Complex::new(3.0f64, 4.0);
+        assert_eq!(z.abs(), 5.0);
+        assert_eq!(Complex::new(3e300f64, 4e300).abs(), 5e300);
+        let (r, theta) = z.to_polar();
+        assert!(close(Complex::from_polar(r, theta), z));
+        assert_eq!(Complex::new(-1.0f64, 0.0).arg(), PI);
+
+        // e^(iπ) = -1
+        assert!(close(Complex::new(0.0, PI).exp(), Complex::from(-1.0)));
+        assert!(close(z.ln().exp(), z));
+        assert!(close(Complex::new(-1.0, 0.0).ln(), Complex::new(0.0, PI)));
+
+        assert_eq!(Complex::new(-4.0f64, 0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_eq!(Complex::new(-4.0f64, -0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_eq!(Complex::new(-3.0f64, -4.0).sqrt(), Complex::new(1.0, -2.0));
+        assert_eq!(z.sqrt(), Complex::new(2.0, 1.0));
+        let w = Complex::new(-1e-10f64, 1.0);
+        assert!(close(w.sqrt() * w.sqrt(), w));
+
+        assert!(close(z.powc(Complex::from(2.0)), z * z));
+        assert!(close(z.powf(0.5), z.sqrt()));
+        // i^i = e^(-π/2)
+        let i = Complex::<f64>::i();
+        assert!(close(i.powc(i), Complex::from((-PI / 2.0).exp())));
+        assert_eq!(Complex::<f64>::from(0.0).powc(i), Complex::from(0.0));
+        assert_eq!(Complex::<f64>::from(0.0).powf(0.0), Complex::from(1.0));
+    }
+
+    #[test]
+    fn test_complex_mat() {
+        use gk_math::base::complex::Complex;
+        use gk_math::base::matrix::Mat;
+
+        let c = |re: f64, im: f64| Complex::new(re, im);
+
+        let a = Mat::new([[c(1.0, 0.0), c(2.0, 1.0)], [c(0.0, -3.0), c(4.0, 2.0)], [c(1.0, 1.0), c(0.0, 0.0)]]);
+        let adjoint = a.conjugate_transpose();
+        assert_eq!(adjoint, Mat::new([[c(1.0, 0.0), c(0.0, 3.0), c(1.0, -1.0)], [c(2.0, -1.0), c(4.0, -2.0), c(0.0, 0.0)]]));
+        assert_eq!(adjoint.conjugate_transpose(), a);
+
+        let h = Mat::new([[c(2.0, 0.0), c(1.0, -1.0)], [c(1.0, 1.0), c(3.0, 0.0)]]);
+        assert!(h.is_hermitian(0.0));
+        assert!(!Mat::new([[c(2.0, 1.0), c(0.0, 0.0)], [c(0.0, 0.0), c(3.0, 0.0)]]).is_hermitian(1e-12));
+        assert!((adjoint * a).is_hermitian(1e-12));
+
+        // the Fourier matrix scaled by 1/sqrt(n) is unitary
+        let w = Complex::from_polar(1.0, -2.0 * std::f64::consts::PI / 4.0);
+        let fourier = Mat::<Complex<f64>, 4, 4>::from_fn(|i, j| w.powf((i * j) as f64) * 0.5);
+        assert!(fourier.is_unitary(1e-14));
+        assert!(!h.is_unitary(1e-12));
+
+        let x = Mat::new([[c(1.0, 0.0)], [c(0.0, 1.0)], [c(-1.0, 0.0)], [c(0.0, -1.0)]]);
+        let spectrum = fourier * x;
+        assert!((spectrum[1][0] - c(2.0, 0.0)).abs() < 1e-14);
+        assert!(spectrum[0][0].abs() < 1e-14);
+        assert_eq!(Mat::<Complex<f64>, 2, 2>::identity() * h, h);
+    }
+}
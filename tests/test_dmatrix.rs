@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_dmat_arithmetic() {
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::base::matrix::Mat;
+
+        let a = DMat::from(Mat::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]));
+        let b = DMat::new(2, 1, vec![1.0, 1.0]).unwrap();
+
+        assert_eq!(a.shape(), (3, 2));
+        assert_eq!(a[(2, 1)], 6.0);
+        assert_eq!(&a * &b, DMat::new(3, 1, vec![3.0, 7.0, 11.0]).unwrap());
+        assert_eq!(a.clone() + a.clone(), a.clone() * 2.0);
+        assert_eq!(a.transpose().to_mat::<2, 3>(), Ok(Mat::new([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]])));
+        assert!(a.to_mat::<2, 2>().is_err());
+        assert!(DMat::new(2, 2, vec![1.0]).is_err());
+    }
+
+    #[test]
+    fn test_dmat_blocks() {
+        use gk_math::base::dmatrix::DMat;
+
+        let a = DMat::from_fn(2, 2, |i, j| (i * 2 + j) as i32);
+        let b = DMat::from_elem(2, 1, 9);
+
+        let ab = a.hstack(&b).unwrap();
+        assert_eq!(ab, DMat::new(2, 3, vec![0, 1, 9, 2, 3, 9]).unwrap());
+        assert_eq!(a.vstack(&a).unwrap().shape(), (4, 2));
+        assert!(a.vstack(&b).is_err());
+        assert_eq!(ab.submatrix(0, 1, 2, 2).unwrap(), DMat::new(2, 2, vec![1, 9, 3, 9]).unwrap());
+
+        let mut c = DMat::zeros(3, 3);
+        c.set_submatrix(1, 1, &a).unwrap();
+        assert_eq!(c, DMat::new(3, 3, vec![0, 0, 0, 0, 0, 1, 0, 2, 3]).unwrap());
+        assert!(c.set_submatrix(2, 2, &a).is_err());
+
+        let h = DMat::identity(2);
+        let constraint = DMat::new(1, 2, vec![1, 1]).unwrap();
+        let kkt = DMat::kkt(&h, &constraint).unwrap();
+        assert_eq!(kkt, DMat::new(3, 3, vec![1, 0, 1, 0, 1, 1, 1, 1, 0]).unwrap());
+        assert_eq!(DMat::from_blocks(&[&[&h, &constraint.transpose()], &[&constraint, &DMat::zeros(1, 1)]]).unwrap(), kkt);
+        assert!(DMat::from_blocks(&[&[&h, &constraint]]).is_err());
+        assert_eq!(DMat::<f64>::from_blocks(&[&[]]), Err("Empty block row"));
+    }
+
+    #[test]
+    fn test_mat_blocks() {
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[1, 2], [3, 4]]);
+        let b = Mat::new([[5], [6]]);
+
+        let ab: Mat<i32, 2, 3> = a.hstack(&b);
+        assert_eq!(ab, Mat::new([[1, 2, 5], [3, 4, 6]]));
+
+        let stacked: Mat<i32, 4, 2> = a.vstack(&a);
+        assert_eq!(stacked.submatrix::<2, 0, 2, 2>(), a);
+
+        let mut m = Mat::<i32, 3, 3>::zeros();
+        m.set_submatrix::<1, 1, 2, 2>(&a);
+        assert_eq!(m, Mat::new([[0, 0, 0], [0, 1, 2], [0, 3, 4]]));
+
+        let mut n = Mat::<i32, 3, 3>::zeros();
+        n.set_block(1, 1, &a);
+        assert_eq!(n, m);
+
+        let kkt: Mat<i32, 3, 3> = Mat::<i32, 2, 2>::identity().kkt(&Mat::new([[1, 1]]));
+        assert_eq!(kkt, Mat::new([[1, 0, 1], [0, 1, 1], [1, 1, 0]]));
+    }
+
+    #[test]
+    fn test_kronecker_vec() {
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+
+        let a = Mat::new([[1, 2], [3, 4]]);
+        let b = Mat::new([[0, 5], [6, 7]]);
+        let expected = Mat::new([
+            [0, 5, 0, 10],
+            [6, 7, 12, 14],
+            [0, 15, 0, 20],
+            [18, 21, 24, 28],
+        ]);
+
+        let k: Mat<i32, 4, 4> = a.kronecker(&b);
+        assert_eq!(k, expected);
+        assert_eq!(DMat::from(a).kronecker(&DMat::from(b)), DMat::from(expected));
+
+        let m = Mat::new([[1, 2, 3], [4, 5, 6]]);
+        let v: Vector<i32, 6> = m.vec();
+        assert_eq!(v, Vector::new([1, 4, 2, 5, 3, 6]));
+        assert_eq!(v.data.to_vec(), m.flatten_col_major());
+        assert_eq!(Mat::<i32, 2, 3>::unvec(&v), m);
+        assert_eq!(DMat::from(m).vec().into_vec(), m.flatten_col_major());
+        assert_eq!(DMat::unvec(&m.flatten_col_major(), 2, 3).unwrap(), DMat::from(m));
+
+        let commutation = Mat::<i32, 6, 6>::commutation::<2, 3>();
+        let vt: Vector<i32, 6> = m.transpose().vec();
+        assert_eq!(commutation * v, vt);
+        assert_eq!(DMat::commutation(2, 3), DMat::from(commutation));
+
+        let outer = Mat::outer(&Vector::new([1, 2]), &Vector::new([3, 4, 5]));
+        assert_eq!(outer, Vector::new([1, 2]).outer(&Vector::new([3, 4, 5])));
+        assert_eq!(DMat::outer(&[1, 2], &[3, 4, 5]), DMat::from(outer));
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_fp_arithmetic() {
+        use gk_math::base::num::{One, Zero};
+        use gk_math::number::finite_field::Fp;
+
+        type F7 = Fp<7>;
+        assert_eq!(F7::new(3) + F7::new(5), F7::new(1));
+        assert_eq!(F7::new(3) - F7::new(5), F7::new(5));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(F7::new(1) / F7::new(3), F7::new(5));
+        assert_eq!(-F7::new(2), F7::new(5));
+        assert_eq!(F7::from(-1i64), F7::new(6));
+        for a in 1..7 {
+            let a = F7::new(a);
+            assert_eq!(a * a.inv(), F7::one());
+        }
+        assert!(F7::new(14).is_zero());
+
+        const P: u64 = (1 << 61) - 1;
+        let a = Fp::<P>::new(123456789123456789);
+        assert_eq!(a / a, Fp::one());
+        assert_eq!(a.pow(P - 1), Fp::one());
+        assert_eq!(format!("{:?}", F7::new(3)), "3 (mod 7)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Fp division by zero")]
+    fn test_fp_division_by_zero() {
+        use gk_math::base::num::{One, Zero};
+        use gk_math::number::finite_field::Fp;
+
+        let _ = Fp::<7>::one() / Fp::zero();
+    }
+
+    #[test]
+    fn test_gf2n_arithmetic() {
+        use gk_math::base::num::One;
+        use gk_math::number::finite_field::{Gf2n, Gf256};
+
+        // FIPS-197 examples
+        assert_eq!(Gf256::new(0x57) + Gf256::new(0x83), Gf256::new(0xd4));
+        assert_eq!(Gf256::new(0x57) * Gf256::new(0x83), Gf256::new(0xc1));
+        assert_eq!(Gf256::new(0x57) * Gf256::new(0x13), Gf256::new(0xfe));
+        assert_eq!(Gf256::new(0x53).inv(), Gf256::new(0xca));
+        for a in 1..256 {
+            let a = Gf256::new(a);
+            assert_eq!(a * a.inv(), Gf256::one());
+            assert_eq!(-a, a);
+        }
+        // x^8 reduces to x^4 + x^3 + x + 1
+        assert_eq!(Gf256::new(0x100), Gf256::new(0x1b));
+
+        // x is primitive modulo x^4 + x + 1
+        type Gf16 = Gf2n<4, 0x13>;
+        let x = Gf16::new(2);
+        let orders: Vec<u64> = (1..=15).filter(|&k| x.pow(k) == Gf16::one()).collect();
+        assert_eq!(orders, vec![15]);
+        assert_eq!(format!("{:?}", Gf16::new(0xb)), "0xb in GF(2^4)");
+    }
+
+    #[test]
+    fn test_fp_linear_algebra() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::num::Zero;
+        use gk_math::number::finite_field::Fp;
+
+        type F7 = Fp<7>;
+        let f = |rows: [[u64; 3]; 3]| Mat::new(rows.map(|row| row.map(F7::new)));
+
+        // determinant 1
+        let a = f([[1, 2, 3], [0, 1, 4], [5, 6, 0]]);
+        assert_eq!(a.rank(), 3);
+        let inv = a.inverse_exact().unwrap();
+        assert_eq!(a * inv, Mat::identity());
+        assert!(a.nullspace().is_empty());
+
+        // third row is twice the first plus the second
+        let b = f([[1, 2, 3], [4, 5, 6], [6, 2, 5]]);
+        assert_eq!(b.rank(), 2);
+        assert!(b.inverse_exact().is_none());
+        let kernel = b.nullspace();
+        assert_eq!(kernel.len(), 1);
+        assert!((b * kernel[0]).data.iter().all(|x| x.is_zero()));
+        assert!(!kernel[0].data.iter().all(|x| x.is_zero()));
+
+        let (r, pivots) = b.rref();
+        assert_eq!(pivots, vec![0, 1]);
+        assert_eq!(r.data[0][0], F7::new(1));
+        assert_eq!(r.data[1][1], F7::new(1));
+        assert!(r.data[2].iter().all(|x| x.is_zero()));
+    }
+
+    #[test]
+    fn test_shamir_reconstruction() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::number::finite_field::Fp;
+
+        type F = Fp<{ (1 << 61) - 1 }>;
+        // secret 42 as the constant term of a random quadratic
+        let coeffs = [F::new(42), F::new(987654321), F::new(1234567)];
+        let share = |x: F| coeffs[0] + coeffs[1] * x + coeffs[2] * x * x;
+        let xs = [F::new(3), F::new(7), F::new(11)];
+        let ys = Mat::new([[share(xs[0])], [share(xs[1])], [share(xs[2])]]);
+
+        let v: Mat<F, 3, 3> = Mat::vandermonde(xs);
+        let recovered = v.inverse_exact().unwrap() * ys;
+        assert_eq!(recovered.data, [[coeffs[0]], [coeffs[1]], [coeffs[2]]]);
+    }
+
+    #[test]
+    fn test_gf256_linear_algebra() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::num::Zero;
+        use gk_math::number::finite_field::Gf256;
+
+        // Reed–Solomon style Vandermonde matrix on distinct nodes
+        let nodes = [1, 2, 3, 4, 5].map(Gf256::new);
+        let v: Mat<Gf256, 5, 5> = Mat::vandermonde(nodes);
+        assert_eq!(v.rank(), 5);
+        let inv = v.inverse_exact().unwrap();
+        assert_eq!(v * inv, Mat::identity());
+        assert_eq!(inv * v, Mat::identity());
+
+        // repeated node, the two rows coincide
+        let w: Mat<Gf256, 3, 4> = Mat::vandermonde([2, 9, 2].map(Gf256::new));
+        assert_eq!(w.rank(), 2);
+        let kernel = w.nullspace();
+        assert_eq!(kernel.len(), 2);
+        for x in kernel {
+            assert!((w * x).data.iter().all(|e| e.is_zero()));
+        }
+    }
+
+    #[test]
+    fn test_bitmat() {
+        use gk_math::base::bitmatrix::BitMat;
+
+        let mut a = BitMat::from_fn(3, 3, |i, j| i == j || j == i + 1);
+        assert!(a.get(0, 1) && !a.get(1, 0));
+        a.xor_row(0, 1);
+        assert_eq!(a, BitMat::from_fn(3, 3, |i, j| [[1, 0, 1], [0, 1, 1], [0, 0, 1]][i][j] == 1));
+        a.swap_rows(0, 2);
+        assert!(a.get(0, 2) && !a.get(0, 0));
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(format!("{:?}", BitMat::identity(2)), "10\n01\n");
+
+        // rows 0 and 1 sum to row 2
+        let b = BitMat::from_fn(3, 4, |i, j| [[1, 1, 0, 1], [0, 1, 1, 0], [1, 0, 1, 1]][i][j] == 1);
+        assert_eq!(b.rank(), 2);
+        let kernel = b.nullspace();
+        assert_eq!(kernel.rows(), 2);
+        assert_eq!(&b * &kernel.transpose(), BitMat::new(3, 2));
+
+        let singular = BitMat::from_fn(3, 3, |i, j| b.get(i, j));
+        assert!(singular.inverse().is_none());
+        assert_eq!(BitMat::identity(70).inverse(), Some(BitMat::identity(70)));
+    }
+
+    #[test]
+    fn test_bitmat_large() {
+        use gk_math::base::bitmatrix::BitMat;
+
+        // xorshift keeps the test deterministic
+        let mut state = 0x9e3779b97f4a7c15u64;
+        let mut bit = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state & 1 == 1
+        };
+
+        let n = 150;
+        let a = BitMat::from_fn(n, n, |_, _| bit());
+        let (r, pivots) = a.rref();
+        assert_eq!(pivots.len(), a.rank());
+        match a.inverse() {
+            Some(inv) => {
+                assert_eq!(&a * &inv, BitMat::identity(n));
+                assert_eq!(&inv * &a, BitMat::identity(n));
+            }
+            None => assert!(pivots.len() < n),
+        }
+        for (row, &col) in pivots.iter().enumerate() {
+            assert!(r.get(row, col));
+            assert!((0..n).filter(|&i| r.get(i, col)).count() == 1);
+        }
+
+        // 100 equations in 170 unknowns have at least 70 independent solutions
+        let b = BitMat::from_fn(100, 170, |_, _| bit());
+        let kernel = b.nullspace();
+        assert_eq!(kernel.rows(), 170 - b.rank());
+        assert!(kernel.rows() >= 70);
+        assert_eq!(kernel.rank(), kernel.rows());
+        let zero = &b * &kernel.transpose();
+        assert_eq!(zero, BitMat::new(100, kernel.rows()));
+    }
+}
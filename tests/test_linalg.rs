@@ -0,0 +1,390 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_mat_norms() {
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[1.0, -2.0], [-3.0, 4.0]]);
+
+        assert_eq!(a.norm_frobenius(), 30.0f64.sqrt());
+        assert_eq!(a.norm_1(), 6.0);
+        assert_eq!(a.norm_inf(), 7.0);
+        // singular values of a are sqrt(15 ± sqrt(221))
+        assert!((a.norm_2() - (15.0 + 221.0f64.sqrt()).sqrt()).abs() < 1e-9);
+        assert_eq!(Mat::<f64, 3, 2>::zeros().norm_2(), 0.0);
+        assert!((Mat::<f64, 2, 3>::new([[3.0, 0.0, 0.0], [0.0, 0.0, -4.0]]).norm_2() - 4.0).abs() < 1e-9);
+
+        let nan = Mat::new([[f64::NAN, 0.0], [0.0, 1.0]]);
+        assert!(nan.norm_frobenius().is_nan());
+        assert!(nan.norm_1().is_nan());
+        assert!(nan.norm_inf().is_nan());
+        assert!(nan.norm_2().is_nan());
+    }
+
+    #[test]
+    fn test_mat_predicates() {
+        use gk_math::base::matrix::Mat;
+
+        let s = Mat::new([[2.0, 1.0], [1.0 + 1e-12, 3.0]]);
+        assert!(s.is_symmetric(1e-9));
+        assert!(!s.is_symmetric(0.0));
+
+        let (c, sn) = (0.6, 0.8);
+        let rotation = Mat::new([[c, -sn], [sn, c]]);
+        assert!(rotation.is_orthogonal(1e-12));
+        assert!(!s.is_orthogonal(1e-12));
+        assert!(!Mat::new([[f64::NAN, 0.0], [0.0, 1.0]]).is_orthogonal(1e-9));
+        assert!(!Mat::new([[1.0, f64::NAN], [0.0, 1.0]]).is_symmetric(1e-9));
+
+        assert!(Mat::diag([1, 2, 3]).is_diagonal());
+        assert!(Mat::new([[1, 2], [0, 3], [0, 0]]).is_upper_triangular());
+        assert!(!Mat::new([[1, 2], [1, 3]]).is_upper_triangular());
+        assert!(Mat::new([[1, 0, 0], [2, 3, 0]]).is_lower_triangular());
+        assert!(s.is_finite());
+        assert!(!(s * f64::NAN).is_finite());
+    }
+
+    #[test]
+    fn test_mat_inverse() {
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]);
+        let inv = a.inverse().unwrap();
+        assert!((a * inv - Mat::identity()).max_abs_element() < 1e-12);
+        assert!(Mat::new([[1.0, 2.0], [2.0, 4.0]]).inverse().is_none());
+
+        let b = Mat::new([[1.0], [2.0], [3.0]]);
+        let x = a.solve(&b).unwrap();
+        assert!((a * x - b).max_abs_element() < 1e-12);
+    }
+
+    #[test]
+    fn test_mat_pow() {
+        use gk_math::base::matrix::Mat;
+
+        let fib = Mat::new([[1u64, 1], [1, 0]]);
+        assert_eq!(fib.pow(0), Mat::identity());
+        assert_eq!(fib.pow(1), fib);
+        assert_eq!(fib.pow(90).data[0][1], 2880067194370816120);
+    }
+
+    #[test]
+    fn test_mat_expm_logm_sqrtm() {
+        use gk_math::base::matrix::Mat;
+
+        assert_eq!(Mat::<f64, 3, 3>::zeros().expm(), Some(Mat::identity()));
+
+        let nilpotent = Mat::new([[0.0, 1.0], [0.0, 0.0]]);
+        assert!((nilpotent.expm().unwrap() - Mat::new([[1.0, 1.0], [0.0, 1.0]])).max_abs_element() < 1e-15);
+
+        // exp of a rotation generator with a large angle exercises the squaring
+        let t = 10.0f64;
+        let rotation = Mat::new([[0.0, -t], [t, 0.0]]).expm().unwrap();
+        let expected = Mat::new([[t.cos(), -t.sin()], [t.sin(), t.cos()]]);
+        assert!((rotation - expected).max_abs_element() < 1e-12);
+
+        let d = Mat::diag([1.0, -2.0, 0.5]).expm().unwrap();
+        assert!((d - Mat::diag([1f64.exp(), (-2f64).exp(), 0.5f64.exp()])).max_abs_element() < 1e-14);
+
+        let spd = Mat::new([[4.0, 1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 2.0]]);
+        let root = spd.sqrtm().unwrap();
+        assert!((root * root - spd).max_abs_element() < 1e-12);
+        assert!(root.is_symmetric(1e-12));
+
+        let log = spd.logm().unwrap();
+        assert!((log.expm().unwrap() - spd).max_abs_element() < 1e-11);
+
+        let small = Mat::new([[0.1, 0.3], [-0.2, 0.05]]);
+        assert!((small.expm().unwrap().logm().unwrap() - small).max_abs_element() < 1e-13);
+
+        assert!(Mat::diag([-1.0, 1.0]).sqrtm().is_none());
+        assert!(Mat::diag([f64::INFINITY, 1.0]).expm().is_none());
+        assert!(Mat::new([[f64::NAN, 0.0], [0.0, 1.0]]).expm().is_none());
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        use gk_math::base::matrix::Mat;
+
+        let l = Mat::new([[2.0, 0.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 3.0, 4.0]]);
+        let b = Mat::new([[2.0, 4.0], [3.0, 1.0], [10.0, 0.0]]);
+
+        let x = l.solve_lower_triangular(&b, false).unwrap();
+        assert!((l * x - b).max_abs_element() < 1e-14);
+
+        let u = l.transpose();
+        let x = u.solve_upper_triangular(&b, false).unwrap();
+        assert!((u * x - b).max_abs_element() < 1e-14);
+
+        // the diagonal is ignored with unit_diagonal
+        let mut unit = l;
+        for i in 0..3 {
+            unit.data[i][i] = 1.0;
+        }
+        assert_eq!(l.solve_lower_triangular(&b, true), unit.solve_lower_triangular(&b, false));
+        assert!(Mat::new([[0.0, 0.0], [1.0, 1.0]]).solve_lower_triangular(&Mat::new([[1.0], [1.0]]), false).is_none());
+    }
+
+    #[test]
+    fn test_tridiagonal_solve() {
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::linalg::triangular::{solve_cyclic_tridiagonal, solve_tridiagonal};
+
+        let (lower, diag, upper) = ([1.0, 1.0, 1.0, 1.0], [4.0, 4.0, 4.0, 4.0, 4.0], [1.0, 2.0, 1.0, 2.0]);
+        let rhs = [1.0f64, 2.0, 3.0, 4.0, 5.0];
+
+        let dense = DMat::from_fn(5, 5, |i, j| match j as isize - i as isize {
+            0 => diag[i],
+            1 => upper[i],
+            -1 => lower[j],
+            _ => 0.0,
+        });
+
+        let x = solve_tridiagonal(&lower, &diag, &upper, &rhs).unwrap();
+        let ax = &dense * &DMat::new(5, 1, x).unwrap();
+        assert!(ax.iter().zip(&rhs).all(|(a, b)| (a - b).abs() < 1e-14));
+
+        let mut cyclic = dense.clone();
+        cyclic[(0, 4)] = 0.5;
+        cyclic[(4, 0)] = -1.0;
+        let x = solve_cyclic_tridiagonal(&lower, &diag, &upper, 0.5, -1.0, &rhs).unwrap();
+        let ax = &cyclic * &DMat::new(5, 1, x).unwrap();
+        assert!(ax.iter().zip(&rhs).all(|(a, b)| (a - b).abs() < 1e-14));
+
+        assert!(solve_tridiagonal(&lower, &diag, &upper[..3], &rhs).is_err());
+        assert!(solve_tridiagonal(&[1.0], &[0.0, 1.0], &[1.0], &[1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_special_matrices() {
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+
+        let t = Mat::<i32, 3, 4>::toeplitz([1, 2, 3], [9, 4, 5, 6]);
+        assert_eq!(t, Mat::new([[1, 4, 5, 6], [2, 1, 4, 5], [3, 2, 1, 4]]));
+        assert_eq!(DMat::from(t), DMat::toeplitz(&[1, 2, 3], &[9, 4, 5, 6]));
+
+        let h = Mat::<i32, 3, 3>::hankel([1, 2, 3], [9, 4, 5]);
+        assert_eq!(h, Mat::new([[1, 2, 3], [2, 3, 4], [3, 4, 5]]));
+        assert_eq!(DMat::from(h), DMat::hankel(&[1, 2, 3], &[9, 4, 5]));
+
+        let c = Mat::circulant([1, 2, 3]);
+        assert_eq!(c, Mat::new([[1, 3, 2], [2, 1, 3], [3, 2, 1]]));
+        assert_eq!(DMat::from(c), DMat::circulant(&[1, 2, 3]));
+
+        let v = Mat::<i32, 3, 4>::vandermonde([1, 2, 3]);
+        assert_eq!(v, Mat::new([[1, 1, 1, 1], [1, 2, 4, 8], [1, 3, 9, 27]]));
+        assert_eq!(DMat::from(v), DMat::vandermonde(&[1, 2, 3], 4));
+
+        let hilbert = Mat::<f64, 2, 3>::hilbert();
+        assert_eq!(hilbert, Mat::new([[1.0, 0.5, 1.0 / 3.0], [0.5, 1.0 / 3.0, 0.25]]));
+        assert_eq!(DMat::<f64>::hilbert(3), DMat::from(Mat::<f64, 3, 3>::hilbert()));
+
+        // x^3 - 6x^2 + 11x - 6 = (x - 1)(x - 2)(x - 3)
+        let companion = Mat::companion([-6.0, 11.0, -6.0]);
+        assert_eq!(companion, Mat::new([[0.0, 0.0, 6.0], [1.0, 0.0, -11.0], [0.0, 1.0, 6.0]]));
+        assert_eq!(DMat::from(companion), DMat::companion(&[-6.0, 11.0, -6.0]));
+        // [1, r, r^2] is a left eigenvector for every root r
+        for root in [1.0f64, 2.0, 3.0] {
+            let v = Vector::new([1.0, root, root * root]);
+            assert_eq!(v * companion, v * root);
+        }
+    }
+
+    #[test]
+    fn test_structured_solve() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::linalg::special::{solve_toeplitz_symmetric, solve_vandermonde};
+
+        let r = [4.0, 1.0, 0.5, 0.25, 0.125];
+        let b = [1.0f64, -2.0, 3.0, 0.5, 2.0];
+        let x = solve_toeplitz_symmetric(&r, &b).unwrap();
+        let a = Mat::<f64, 5, 5>::toeplitz(r, r);
+        let expected = a.solve(&Mat::new(b.map(|x| [x]))).unwrap();
+        assert!(x.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-14));
+
+        assert_eq!(solve_toeplitz_symmetric(&[2.0], &[1.0]), Ok(vec![0.5]));
+        assert!(solve_toeplitz_symmetric(&[1.0, 1.0], &[1.0, 2.0]).is_err());
+        assert!(solve_toeplitz_symmetric(&r, &b[..4]).is_err());
+
+        // 1 - 2x + 3x^2 + x^3
+        let nodes = [-1.0, 0.5, 2.0, 3.0];
+        let values = nodes.map(|x: f64| 1.0 - 2.0 * x + 3.0 * x * x + x * x * x);
+        let coeffs = solve_vandermonde(&nodes, &values).unwrap();
+        assert!(coeffs.iter().zip([1.0, -2.0, 3.0, 1.0]).all(|(a, b)| (a - b).abs() < 1e-12));
+        assert!(solve_vandermonde(&[1.0, 2.0, 1.0], &[1.0, 2.0, 3.0]).is_err());
+    }
+
+    #[test]
+    fn test_hilbert_conditioning() {
+        use gk_math::base::matrix::Mat;
+
+        // the condition number of the n x n Hilbert matrix grows like e^(3.5 n),
+        // the solve stays accurate only up to eps * cond
+        fn relative_error<const N: usize>() -> f64 {
+            let h = Mat::<f64, N, N>::hilbert();
+            let x = Mat::<f64, N, 1>::ones();
+            let solved = h.solve(&(h * x)).unwrap();
+            (solved - x).max_abs_element()
+        }
+
+        assert!(relative_error::<3>() < 1e-13);
+        assert!(relative_error::<6>() < 1e-9);
+        assert!(relative_error::<10>() < 1e-2);
+        assert!(relative_error::<10>() > relative_error::<3>());
+
+        let h = Mat::<f64, 6, 6>::hilbert();
+        assert!(h.is_symmetric(0.0));
+        let inverse = h.inverse().unwrap();
+        // the inverse of a Hilbert matrix has integer elements, (H^-1)[0][0] = n^2
+        assert!((inverse[0][0] - 36.0).abs() < 1e-6);
+        assert!((h * inverse - Mat::identity()).max_abs_element() < 1e-8);
+    }
+
+    #[test]
+    fn test_householder() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+        use gk_math::linalg::householder::Householder;
+
+        let x = Vector::new([3.0f64, 1.0, 5.0, 1.0]);
+        let h = Householder::from_vector(&x);
+        let hx = h.to_mat() * x;
+        assert!((hx[0] + 6.0).abs() < 1e-14);
+        assert!(hx.iter().skip(1).all(|x| x.abs() < 1e-14));
+        assert!(h.to_mat().is_orthogonal(1e-14));
+        assert!(h.to_mat().is_symmetric(1e-14));
+
+        let a = Mat::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+        let mut left = a;
+        h.apply_left(&mut left);
+        assert!((left - h.to_mat() * a).max_abs_element() < 1e-13);
+        let mut right = a.transpose();
+        h.apply_right(&mut right);
+        assert!((right - a.transpose() * h.to_mat()).max_abs_element() < 1e-13);
+
+        // the leading components are left alone
+        let h = Householder::from_subvector(&x, 2);
+        let hx = h.to_mat() * x;
+        assert_eq!([hx[0], hx[1]], [3.0, 1.0]);
+        assert!((hx[2] + 26f64.sqrt()).abs() < 1e-14 && hx[3].abs() < 1e-14);
+
+        // already aligned with e0
+        let h = Householder::from_vector(&Vector::new([-2.0, 0.0, 0.0, 0.0]));
+        assert_eq!(h.to_mat(), Mat::identity());
+    }
+
+    #[test]
+    fn test_givens() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::linalg::givens::Givens;
+
+        let g = Givens::new(3.0f64, 4.0);
+        assert_eq!((g.c, g.s), (0.6, 0.8));
+        assert_eq!(Givens::new(2.0, 0.0), Givens { c: 1.0, s: 0.0 });
+        let big = Givens::new(3e200f64, 4e200);
+        assert!((big.c - 0.6).abs() < 1e-15 && (big.s - 0.8).abs() < 1e-15);
+
+        let a = Mat::new([[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+        let g = Givens::new(a[0][0], a[2][0]);
+        let mut left = a;
+        g.apply_left(&mut left, 0, 2);
+        assert!(left[2][0].abs() < 1e-14);
+        assert!((left - g.to_mat::<3>(0, 2) * a).max_abs_element() < 1e-14);
+        assert!(g.to_mat::<3>(0, 2).is_orthogonal(1e-15));
+
+        let mut right = a;
+        g.apply_right(&mut right, 0, 2);
+        assert!((right - a * g.to_mat::<3>(0, 2).transpose()).max_abs_element() < 1e-14);
+
+        // QR by Givens rotations
+        let mut r = a;
+        for j in 0..2 {
+            for i in j + 1..3 {
+                Givens::new(r[j][j], r[i][j]).apply_left(&mut r, j, i);
+            }
+        }
+        assert!(r.map(|x| if x.abs() < 1e-14 { 0.0 } else { x }).is_upper_triangular());
+        assert!(((r.transpose() * r) - a.transpose() * a).max_abs_element() < 1e-12);
+    }
+
+    #[test]
+    fn test_orthonormalize_columns() {
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[1.0f64, 1.0, 0.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [1.0, 1.0, 1.0]]);
+        let (q, rank) = a.orthonormalize_columns();
+        assert_eq!(rank, 3);
+        assert!(q.is_orthogonal(1e-14));
+        // Q spans the columns of A, QQᵀA = A
+        assert!((q * (q.transpose() * a) - a).max_abs_element() < 1e-14);
+
+        // the Hilbert columns are nearly dependent, MGS keeps them orthogonal to sqrt(eps) * cond
+        let (q, rank) = Mat::<f64, 6, 6>::hilbert().orthonormalize_columns();
+        assert_eq!(rank, 6);
+        assert!(q.is_orthogonal(1e-5));
+
+        let b = Mat::new([[1.0f64, 2.0, 0.0, 1.0], [2.0, 4.0, 0.0, 0.0], [3.0, 6.0, 0.0, 1.0]]);
+        let (q, rank) = b.orthonormalize_columns();
+        assert_eq!(rank, 2);
+        assert_eq!(q.col(1).norm_squared(), 0.0);
+        assert_eq!(q.col(2).norm_squared(), 0.0);
+        assert!(q.col(0).dot(&q.col(3)).abs() < 1e-15);
+        assert!((q.col(3).norm_squared() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_qr() {
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[12.0f64, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0], [1.0, 2.0, 3.0]]);
+        let (q, r) = a.qr();
+        assert!(q.is_orthogonal(1e-14));
+        assert!(r.is_upper_triangular());
+        assert!((q * r - a).max_abs_element() < 1e-12);
+
+        let d = DMat::from(a);
+        let (dq, dr) = d.qr();
+        assert_eq!(dq.shape(), (4, 4));
+        assert_eq!(dr.shape(), (4, 3));
+        for i in 0..4 {
+            for j in 0..3 {
+                assert!((dr[(i, j)] - r.data[i][j]).abs() < 1e-12);
+            }
+            for j in 0..4 {
+                assert!((dq[(i, j)] - q.data[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_least_squares() {
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::base::matrix::Mat;
+
+        // the least-squares residual is orthogonal to the columns
+        let a = Mat::new([[1.0f64, 1.0], [1.0, 2.0], [1.0, 3.0], [1.0, 4.0]]);
+        let b = Mat::new([[6.0], [5.0], [7.0], [10.0]]);
+        let x = a.solve_least_squares(&b).unwrap();
+        assert!((x.data[0][0] - 3.5).abs() < 1e-13);
+        assert!((x.data[1][0] - 1.4).abs() < 1e-13);
+        assert!((a.transpose() * (a * x - b)).max_abs_element() < 1e-12);
+
+        // consistent square systems agree with the LU solve
+        let s = Mat::new([[4.0f64, 1.0, 2.0], [1.0, 3.0, 0.0], [2.0, 0.0, 5.0]]);
+        let rhs = Mat::new([[1.0, 0.0], [2.0, 1.0], [3.0, 0.0]]);
+        let lu = s.solve(&rhs).unwrap();
+        assert!((s.solve_least_squares(&rhs).unwrap() - lu).max_abs_element() < 1e-14);
+
+        let dependent = Mat::new([[1.0f64, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        assert!(dependent.solve_least_squares(&Mat::new([[1.0], [2.0], [3.0]])).is_none());
+
+        let da = DMat::from(a);
+        let dx = da.solve_least_squares(&DMat::from(b)).unwrap();
+        assert_eq!(dx.shape(), (2, 1));
+        assert!((dx[(0, 0)] - 3.5).abs() < 1e-13 && (dx[(1, 0)] - 1.4).abs() < 1e-13);
+        assert!(DMat::from(dependent).solve_least_squares(&DMat::zeros(3, 1)).is_none());
+    }
+}
@@ -30,4 +30,158 @@ mod tests {
 
         assert_eq!(m * v, v);
     }
+
+    #[test]
+    fn test_f32_mat_vec_products() {
+        use gk_math::base::f32::{Mat3, Mat3x4, Vec3, Vec4};
+        let m = Mat3::new([[1.0, 2.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]]);
+        let v = Vec3::new(1.0, 1.0, 1.0);
+
+        assert_eq!(m * v, Vec3::new(3.0, 1.0, 2.0));
+        assert_eq!(v * m, Vec3::new(1.0, 3.0, 2.0));
+        let (m_ref, v_ref) = (&m, &v);
+        assert_eq!(m_ref * v_ref, m * v);
+        assert_eq!(v_ref * m_ref, v * m);
+
+        let affine = Mat3x4::new([[1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 2.0], [0.0, 0.0, 1.0, 3.0]]);
+        let p = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+        assert_eq!(affine * p, Vec3::new(2.0, 3.0, 4.0));
+        assert_eq!(v * affine, Vec4::new(1.0, 1.0, 1.0, 6.0));
+    }
+
+    #[test]
+    fn test_mat_element_wise() {
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[1.0, -2.0], [3.0, -4.0]]);
+        let b = Mat::new([[2.0, 2.0], [2.0, 2.0]]);
+
+        assert_eq!(a.hadamard(&b), a * 2.0);
+        assert_eq!(a.component_div(&b), a / 2.0);
+        assert_eq!(a.abs(), Mat::new([[1.0, 2.0], [3.0, 4.0]]));
+        assert_eq!(a.min(&b), Mat::new([[1.0, -2.0], [2.0, -4.0]]));
+        assert_eq!(a.max(&b), Mat::new([[2.0, 2.0], [3.0, 2.0]]));
+        assert_eq!(a.max_abs_element(), 4.0);
+        assert!(Mat::new([[f64::NAN, 0.0], [0.0, 1.0]]).max_abs_element().is_nan());
+        assert!(Mat::new([[1.0, 0.0], [0.0, f64::NAN]]).max_abs_element().is_nan());
+        assert_eq!(a.fold(0.0, |acc, x| acc + x), a.sum());
+        assert_eq!(a.map(|x| x as i32), Mat::new([[1, -2], [3, -4]]));
+        assert_eq!(a.zip_map(b, |x, y| x > y), Mat::new([[false, false], [true, false]]));
+        assert_eq!(Mat::<usize, 2, 3>::from_fn(|r, c| r * 3 + c), Mat::new([[0, 1, 2], [3, 4, 5]]));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 3.0;
+        c /= 2.0;
+        assert_eq!(c, Mat::new([[3.0, 3.0], [3.0, 3.0]]));
+        assert_eq!(-a + a, Mat::zeros());
+    }
+
+    #[test]
+    fn test_mat_cols_rows() {
+        use gk_math::base::f32::{Mat3, Vec3};
+        use gk_math::base::vector::Vector;
+
+        let (t, b, n) = (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, -1.0, 0.0));
+        let basis = Mat3::from_cols([t, b, n]);
+
+        assert_eq!(basis * Vec3::new(0.0, 0.0, 1.0), n);
+        assert_eq!(Vec3::from(basis.col(1)), b);
+        assert_eq!(basis.row(1), Vector::new([0.0, 0.0, -1.0]));
+        assert_eq!(Mat3::from_rows([t, b, n]), basis.transpose());
+        assert_eq!(basis.cols_iter().map(Vec3::from).collect::<Vec<_>>(), vec![t, b, n]);
+
+        let mut m = Mat3::identity();
+        m.set_col(2, Vec3::new(1.0, 2.0, 3.0));
+        m.set_row(0, [4.0, 5.0, 6.0]);
+        assert_eq!(m, Mat3::new([[4.0, 5.0, 6.0], [0.0, 1.0, 2.0], [0.0, 0.0, 3.0]]));
+        assert_eq!(m.diagonal(), [4.0, 1.0, 3.0]);
+        assert_eq!(m.trace(), 8.0);
+        assert_eq!(Vector::new([1.0, 1.0, 1.0]) * m, Vector::new([4.0, 6.0, 11.0]));
+    }
+
+    #[test]
+    fn test_mat_homogeneous() {
+        use gk_math::base::f32::{Mat2, Mat3, Mat4, Vec3};
+
+        let r = Mat3::new([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        let m4 = Mat4::from_mat3(&r);
+
+        assert_eq!(m4 * Vec3::new(1.0, 0.0, 0.0).extend(1.0), Vec3::new(0.0, 1.0, 0.0).extend(1.0));
+        assert_eq!(Mat3::from_mat4(&m4), r);
+        assert_eq!(Mat2::from_mat3(&r), Mat2::new([[0.0, -1.0], [1.0, 0.0]]));
+        assert_eq!(Mat3::from_mat2(&Mat2::from_mat3(&r)), r);
+
+        let block: Mat2 = m4.block(1, 1);
+        assert_eq!(block, Mat2::new([[0.0, 0.0], [0.0, 1.0]]));
+        assert_eq!(m4.submatrix::<1, 1, 2, 2>(), block);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mat_block_out_of_bounds() {
+        use gk_math::base::f32::{Mat2, Mat3};
+
+        let _: Mat2 = Mat3::identity().block(2, 0);
+    }
+
+    #[test]
+    fn test_mat_custom_scalar() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::num::{One, Ring, Zero};
+        use std::ops::{Add, Mul, Sub};
+
+        // integers mod 7, no `Default` or `From<u8>`
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Mod7(u8);
+
+        impl Add for Mod7 {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Mod7((self.0 + rhs.0) % 7)
+            }
+        }
+
+        impl Sub for Mod7 {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Mod7((self.0 + 7 - rhs.0) % 7)
+            }
+        }
+
+        impl Mul for Mod7 {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Mod7((self.0 * rhs.0) % 7)
+            }
+        }
+
+        impl Zero for Mod7 {
+            fn zero() -> Self {
+                Mod7(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl One for Mod7 {
+            fn one() -> Self {
+                Mod7(1)
+            }
+        }
+
+        impl Ring for Mod7 {}
+
+        let m = Mat::new([[Mod7(1), Mod7(1)], [Mod7(1), Mod7(0)]]);
+        assert_eq!(Mat::<Mod7, 2, 2>::identity() * m, m);
+        assert_eq!(Mat::<Mod7, 2, 3>::zeros(), Mat::default());
+        assert_eq!(m.trace(), Mod7(1));
+        // Fibonacci mod 7, [[F(9), F(8)], [F(8), F(7)]] = [[34, 21], [21, 13]]
+        assert_eq!(m.pow(8), Mat::new([[Mod7(6), Mod7(0)], [Mod7(0), Mod7(6)]]));
+        assert!(Mat::<Mod7, 2, 2>::identity().is_diagonal());
+    }
 }
\ No newline at end of file
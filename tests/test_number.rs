@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_sieve() {
+        use gk_math::number::prime::{is_prime, segmented_sieve, sieve};
+
+        assert_eq!(sieve(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(sieve(2), Vec::<u64>::new());
+        assert_eq!(segmented_sieve(0, 30), sieve(30));
+        assert_eq!(sieve(200_000).len(), 17984);
+        // several segments, compared with trial by Miller–Rabin
+        let range = segmented_sieve(1_000_000_000, 1_000_200_000);
+        let expected: Vec<u64> = (1_000_000_000..1_000_200_000).filter(|&n| is_prime(n)).collect();
+        assert_eq!(range, expected);
+        assert_eq!(segmented_sieve(100, 100), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_is_prime() {
+        use gk_math::number::prime::{is_prime, sieve};
+
+        let primes = sieve(10_000);
+        for n in 0..10_000 {
+            assert_eq!(is_prime(n), primes.binary_search(&n).is_ok(), "{}", n);
+        }
+        assert!(is_prime(18446744073709551557)); // largest u64 prime
+        assert!(!is_prime(u64::MAX));
+        // strong pseudoprime to every base up to 23
+        assert!(!is_prime(3825123056546413051));
+        assert!(!is_prime(4294967297)); // 641 * 6700417
+    }
+
+    #[test]
+    fn test_factorize() {
+        use gk_math::number::prime::{factorize, mobius, pollard_rho, totient};
+
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(4294967297), vec![(641, 1), (6700417, 1)]);
+        // product of two 32-bit primes
+        assert_eq!(factorize(4294967291 * 4294967279), vec![(4294967279, 1), (4294967291, 1)]);
+        assert_eq!(factorize(1u64 << 63), vec![(2, 63)]);
+        assert_eq!(factorize(999999999989 * 3 * 3), vec![(3, 2), (999999999989, 1)]);
+
+        let n = 600851475143;
+        let d = pollard_rho(n);
+        assert!(d > 1 && d < n && n % d == 0);
+        assert_eq!(pollard_rho(13), 13);
+
+        assert_eq!(totient(1), 1);
+        assert_eq!(totient(36), 12);
+        assert_eq!(totient(97), 96);
+        assert_eq!((1..=10).map(mobius).collect::<Vec<_>>(), vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1]);
+        // Σ_{d|n} μ(d) = 0 for n > 1
+        assert_eq!((1..=60u64).filter(|d| 60 % d == 0).map(|d| mobius(d) as i32).sum::<i32>(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pollard rho of zero")]
+    fn test_pollard_rho_zero() {
+        use gk_math::number::prime::pollard_rho;
+
+        let _ = pollard_rho(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Möbius function of zero")]
+    fn test_mobius_zero() {
+        use gk_math::number::prime::mobius;
+
+        let _ = mobius(0);
+    }
+
+    #[test]
+    fn test_modular() {
+        use gk_math::number::modular::{crt, ext_gcd, gcd, lcm, mod_inverse, mul_mod, pow_mod};
+
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, 2), None);
+
+        let (g, x, y) = ext_gcd(240, 46).unwrap();
+        assert_eq!((g, 240 * x + 46 * y), (2, 2));
+        let (g, x, y) = ext_gcd(-12, 18).unwrap();
+        assert_eq!((g, -12 * x + 18 * y), (6, 6));
+        assert_eq!(ext_gcd(0, 0).unwrap().0, 0);
+        assert_eq!(ext_gcd(i64::MIN, 0), None);
+        assert_eq!(ext_gcd(0, i64::MIN), None);
+        assert_eq!(ext_gcd(i64::MIN, i64::MIN), None);
+        let (g, x, y) = ext_gcd(i64::MIN, 6).unwrap();
+        assert_eq!((g, i64::MIN as i128 * x as i128 + 6 * y as i128), (2, 2));
+        assert_eq!(ext_gcd(i64::MIN + 1, 0), Some((i64::MAX, -1, 0)));
+
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(6, 9), None);
+        let m = 18446744073709551557;
+        assert_eq!(mul_mod(mod_inverse(m - 2, m).unwrap(), m - 2, m), 1);
+
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(5, 0, 1), 0);
+        // Fermat's little theorem for the largest u64 prime
+        assert_eq!(pow_mod(123456789, m - 1, m), 1);
+
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(5, 0)]), None);
+    }
+
+    #[test]
+    fn test_modint() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::number::modint::ModInt;
+
+        type M = ModInt<1_000_000_007>;
+
+        let a = M::new(1_000_000_006);
+        assert_eq!(a + M::new(2), M::new(1));
+        assert_eq!(M::new(1) - M::new(2), a);
+        assert_eq!(-a, M::new(1));
+        assert_eq!(M::from(-1i64), a);
+        assert_eq!(a * a, M::new(1));
+        assert_eq!(M::new(2).pow(1_000_000_006), M::new(1));
+        assert_eq!(M::new(3).inv().unwrap() * M::new(3), M::new(1));
+        assert_eq!(ModInt::<10>::new(4).inv(), None);
+        assert_eq!(format!("{}", M::new(5)), "5");
+
+        // modular matrix exponentiation through the generic Mat::pow
+        let fib = Mat::new([[M::new(1), M::new(1)], [M::new(1), M::new(0)]]);
+        assert_eq!(fib.pow(90)[0][1], M::new(2880067194370816120 % 1_000_000_007));
+        let big = fib.pow(u32::MAX);
+        assert_eq!(big * fib, fib.pow(u32::MAX - 1) * fib * fib);
+
+        // modulus close to u64::MAX
+        type P = ModInt<18446744073709551557>;
+        assert_eq!(P::new(18446744073709551556) + P::new(18446744073709551556), P::new(18446744073709551555));
+        assert_eq!(P::new(18446744073709551556) * P::new(18446744073709551556), P::new(1));
+    }
+}
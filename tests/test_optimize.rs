@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_levenberg_marquardt_curve_fit() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+        use gk_math::optimize::least_squares::{finite_difference_jacobian, levenberg_marquardt, LeastSquaresOptions, Termination};
+
+        // y = a exp(b t) sampled without noise
+        let t: [f64; 8] = std::array::from_fn(|i| i as f64 * 0.25);
+        let y = t.map(|t| 2.5 * (-1.3 * t).exp());
+        let residual = |p: &Vector<f64, 2>| Vector::<f64, 8>::from_fn(|i| p[0] * (p[1] * t[i]).exp() - y[i]);
+        let jacobian = |p: &Vector<f64, 2>| {
+            Mat::<f64, 8, 2>::from_fn(|i, j| if j == 0 { (p[1] * t[i]).exp() } else { p[0] * t[i] * (p[1] * t[i]).exp() })
+        };
+        let x0 = Vector::new([1.0, 0.0]);
+        let options = LeastSquaresOptions::default();
+
+        let report = levenberg_marquardt(residual, jacobian, &x0, &options);
+        assert!((report.x[0] - 2.5).abs() < 1e-10);
+        assert!((report.x[1] + 1.3).abs() < 1e-10);
+        assert!(report.cost < 1e-20);
+        assert_ne!(report.termination, Termination::MaxIterations);
+
+        let fd = levenberg_marquardt(residual, |p| finite_difference_jacobian(residual, p), &x0, &options);
+        assert!((fd.x - report.x).norm() < 1e-8);
+        let difference = finite_difference_jacobian(residual, &x0) - jacobian(&x0);
+        assert!(difference.max_abs_element() < 1e-9);
+
+        // a fit with a non-zero residual stops at the stationary point
+        let mut noisy = y;
+        noisy[3] += 0.05;
+        let residual = |p: &Vector<f64, 2>| Vector::<f64, 8>::from_fn(|i| p[0] * (p[1] * t[i]).exp() - noisy[i]);
+        let report = levenberg_marquardt(residual, jacobian, &x0, &options);
+        let gradient = residual(&report.x) * jacobian(&report.x);
+        assert!(gradient.norm() < 1e-9);
+        assert!(report.cost > 1e-4);
+    }
+
+    #[test]
+    fn test_levenberg_marquardt_rosenbrock() {
+        use gk_math::autodiff::forward::jacobian;
+        use gk_math::base::num::Real;
+        use gk_math::base::vector::Vector;
+        use gk_math::optimize::least_squares::{gauss_newton, levenberg_marquardt, LeastSquaresOptions, Termination};
+
+        // Rosenbrock's function as the residuals (10 (y - x²), 1 - x)
+        fn residual<T: Real>(p: &Vector<T, 2>) -> Vector<T, 2> {
+            let ten = T::from_f64(10.0);
+            Vector::new([ten * (p[1] - p[0] * p[0]), T::one() - p[0]])
+        }
+        let x0 = Vector::new([-1.2f64, 1.0]);
+        let options = LeastSquaresOptions::default();
+
+        let report = levenberg_marquardt(residual, |p| jacobian(residual, p), &x0, &options);
+        assert!((report.x - Vector::new([1.0, 1.0])).norm() < 1e-10);
+        assert!(report.iterations < 100);
+        assert_ne!(report.termination, Termination::MaxIterations);
+
+        let capped = LeastSquaresOptions { max_iterations: 3, ..options };
+        let report = levenberg_marquardt(residual, |p| jacobian(residual, p), &x0, &capped);
+        assert_eq!(report.termination, Termination::MaxIterations);
+        assert_eq!(report.iterations, 3);
+
+        // the residuals are square and invertible, Gauss-Newton is Newton's method
+        let report = gauss_newton(residual, |p| jacobian(residual, p), &x0, &options);
+        assert!((report.x - Vector::new([1.0, 1.0])).norm() < 1e-12);
+        assert!(report.iterations <= 3);
+    }
+
+    #[test]
+    fn test_gauss_newton_singular() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+        use gk_math::optimize::least_squares::{gauss_newton, levenberg_marquardt, LeastSquaresOptions, Termination};
+
+        // only x + y is determined
+        let residual = |p: &Vector<f64, 2>| Vector::new([p[0] + p[1] - 1.0, 2.0 * (p[0] + p[1]) - 2.0]);
+        let jacobian = |_: &Vector<f64, 2>| Mat::new([[1.0, 1.0], [2.0, 2.0]]);
+        let x0 = Vector::new([3.0, 0.0]);
+        let options = LeastSquaresOptions::default();
+
+        let report = gauss_newton(residual, jacobian, &x0, &options);
+        assert_eq!(report.termination, Termination::Singular);
+        assert_eq!(report.x, x0);
+
+        // the damping keeps the system solvable
+        let report = levenberg_marquardt(residual, jacobian, &x0, &options);
+        assert!((report.x[0] + report.x[1] - 1.0).abs() < 1e-10);
+        assert!(report.cost < 1e-20);
+    }
+
+    #[test]
+    fn test_non_finite_residual() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::optimize::least_squares::{
+            gauss_newton, gauss_newton_dmat, levenberg_marquardt, LeastSquaresOptions, Termination,
+        };
+
+        // the first Gauss-Newton step from e² lands at a negative x where ln is NaN
+        let residual = |p: &Vector<f64, 1>| Vector::new([p[0].ln()]);
+        let jacobian = |p: &Vector<f64, 1>| Mat::new([[1.0 / p[0]]]);
+        let x0 = Vector::new([2f64.exp()]);
+        let options = LeastSquaresOptions::default();
+
+        let report = gauss_newton(residual, jacobian, &x0, &options);
+        assert_eq!(report.termination, Termination::NonFinite);
+        assert_eq!(report.x, x0);
+        assert_eq!(report.iterations, 0);
+        assert!((report.cost - 2.0).abs() < 1e-15);
+
+        // the damping rejects the step and shortens it
+        let report = levenberg_marquardt(residual, jacobian, &x0, &options);
+        assert!((report.x[0] - 1.0).abs() < 1e-10);
+
+        let report = gauss_newton(residual, jacobian, &Vector::new([-1.0]), &options);
+        assert_eq!(report.termination, Termination::NonFinite);
+        assert_eq!(report.iterations, 0);
+
+        let residual = |p: &[f64]| vec![p[0].ln()];
+        let jacobian = |p: &[f64]| DMat::from_elem(1, 1, 1.0 / p[0]);
+        let report = gauss_newton_dmat(residual, jacobian, &x0.data, &options);
+        assert_eq!(report.termination, Termination::NonFinite);
+        assert_eq!(report.x, x0.data.to_vec());
+    }
+
+    #[test]
+    fn test_levenberg_marquardt_dmat() {
+        use gk_math::base::dmatrix::DMat;
+        use gk_math::optimize::least_squares::{
+            finite_difference_jacobian_dmat, gauss_newton_dmat, levenberg_marquardt_dmat, LeastSquaresOptions, Termination,
+        };
+
+        // circle through points on (x - 1)² + (y + 2)² = 3², fitted as (cx, cy, r)
+        let points: Vec<(f64, f64)> = (0..40)
+            .map(|k| {
+                let angle = k as f64 * 0.15;
+                (1.0 + 3.0 * angle.cos(), -2.0 + 3.0 * angle.sin())
+            })
+            .collect();
+        let residual = |p: &[f64]| -> Vec<f64> {
+            points.iter().map(|&(x, y)| ((x - p[0]).powi(2) + (y - p[1]).powi(2)).sqrt() - p[2]).collect()
+        };
+        let jacobian = |p: &[f64]| {
+            DMat::from_fn(points.len(), 3, |i, j| {
+                let (x, y) = points[i];
+                let d = ((x - p[0]).powi(2) + (y - p[1]).powi(2)).sqrt();
+                match j {
+                    0 => (p[0] - x) / d,
+                    1 => (p[1] - y) / d,
+                    _ => -1.0,
+                }
+            })
+        };
+        let x0 = [0.0, 0.0, 1.0];
+        let options = LeastSquaresOptions::default();
+
+        let report = levenberg_marquardt_dmat(residual, jacobian, &x0, &options);
+        for (x, expected) in report.x.iter().zip([1.0, -2.0, 3.0]) {
+            assert!((x - expected).abs() < 1e-10);
+        }
+        assert!(report.cost < 1e-20);
+        assert_ne!(report.termination, Termination::MaxIterations);
+
+        let fd = levenberg_marquardt_dmat(residual, |p| finite_difference_jacobian_dmat(residual, p), &x0, &options);
+        for (x, expected) in fd.x.iter().zip(&report.x) {
+            assert!((x - expected).abs() < 1e-8);
+        }
+        let (exact, approx) = (jacobian(&x0), finite_difference_jacobian_dmat(residual, &x0));
+        assert_eq!(approx.shape(), (40, 3));
+        assert!(exact.iter().zip(approx.iter()).all(|(a, b)| (a - b).abs() < 1e-9));
+
+        let report = gauss_newton_dmat(residual, jacobian, &[0.5, -1.5, 2.5], &options);
+        for (x, expected) in report.x.iter().zip([1.0, -2.0, 3.0]) {
+            assert!((x - expected).abs() < 1e-10);
+        }
+
+        // fewer residuals than parameters
+        let report = gauss_newton_dmat(|p: &[f64]| vec![p[0] + p[1]], |_: &[f64]| DMat::ones(1, 2), &[1.0, 1.0], &options);
+        assert_eq!(report.termination, Termination::Singular);
+        let report = levenberg_marquardt_dmat(|p: &[f64]| vec![p[0] + p[1]], |_: &[f64]| DMat::ones(1, 2), &[1.0, 1.0], &options);
+        assert!(report.cost < 1e-20);
+    }
+}
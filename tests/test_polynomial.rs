@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_polynomial_arithmetic() {
+        use gk_math::base::num::Zero;
+        use gk_math::base::polynomial::Polynomial;
+
+        let p = Polynomial::new(vec![1, 2, 3]);
+        let q = Polynomial::new(vec![-1, 1]);
+        assert_eq!(p.degree(), Some(2));
+        assert_eq!(Polynomial::new(vec![1, 0, 0]).coeffs(), &[1]);
+        assert_eq!(Polynomial::<i32>::zero().degree(), None);
+
+        assert_eq!(p.clone() + q.clone(), Polynomial::new(vec![0, 3, 3]));
+        assert_eq!(p.clone() - p.clone(), Polynomial::zero());
+        assert_eq!(&p * &q, Polynomial::new(vec![-1, -1, -1, 3]));
+        assert_eq!(-q.clone(), Polynomial::new(vec![1, -1]));
+        assert_eq!(p.clone() * 2, Polynomial::new(vec![2, 4, 6]));
+        assert_eq!(Polynomial::from_roots(&[1, 2, 3]), Polynomial::new(vec![-6, 11, -6, 1]));
+
+        assert_eq!(p.eval(2), 17);
+        assert_eq!(p.derivative(), Polynomial::new(vec![2, 6]));
+        assert_eq!(p.compose(&q), Polynomial::new(vec![2, -4, 3]));
+        assert_eq!(format!("{}", Polynomial::new(vec![-3, 1, 0, 2])), "2x^3 + x + -3");
+        assert_eq!(format!("{}", Polynomial::<i32>::zero()), "0");
+    }
+
+    #[test]
+    fn test_polynomial_division() {
+        use gk_math::base::num::Zero;
+        use gk_math::base::polynomial::Polynomial;
+        use gk_math::base::rational::Rational;
+        use gk_math::number::finite_field::Fp;
+
+        let r = |n: i64, d: i64| Rational::new(n, d);
+        let p = Polynomial::new(vec![r(-4, 1), r(0, 1), r(-2, 1), r(1, 1)]);
+        let d = Polynomial::new(vec![r(-3, 1), r(1, 1)]);
+        let (q, rem) = p.div_rem(&d);
+        assert_eq!(q, Polynomial::new(vec![r(3, 1), r(1, 1), r(1, 1)]));
+        assert_eq!(rem, Polynomial::new(vec![r(5, 1)]));
+        assert_eq!(&q * &d + rem, p);
+
+        let half = Polynomial::new(vec![r(1, 1), r(1, 2)]);
+        assert_eq!(half.integral(), Polynomial::new(vec![r(0, 1), r(1, 1), r(1, 4)]));
+        assert_eq!(half.integral().derivative(), half);
+
+        // (x - 1)^2 (x + 2) and (x - 1)(x + 3) share x - 1
+        let a = Polynomial::from_roots(&[r(1, 1), r(1, 1), r(-2, 1)]);
+        let b = Polynomial::from_roots(&[r(1, 1), r(-3, 1)]) * r(5, 1);
+        assert_eq!(a.gcd(&b), Polynomial::from_roots(&[r(1, 1)]));
+        assert_eq!(Polynomial::<Rational<i64>>::zero().gcd(&Polynomial::zero()), Polynomial::zero());
+
+        // over GF(7), x^6 - 1 splits completely
+        type F7 = Fp<7>;
+        let f = |c: u64| F7::new(c);
+        let x6 = Polynomial::new(vec![-f(1), f(0), f(0), f(0), f(0), f(0), f(1)]);
+        let linear = Polynomial::from_roots(&[f(3)]);
+        assert!((&x6 % &linear).is_zero());
+        assert_eq!(x6.gcd(&Polynomial::from_roots(&[f(0), f(2)])), Polynomial::from_roots(&[f(2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Polynomial division by zero")]
+    fn test_polynomial_division_by_zero() {
+        use gk_math::base::num::Zero;
+        use gk_math::base::polynomial::Polynomial;
+
+        let _ = Polynomial::new(vec![1.0f64]).div_rem(&Polynomial::zero());
+    }
+
+    #[test]
+    fn test_polynomial_roots() {
+        use gk_math::base::complex::Complex;
+        use gk_math::base::polynomial::Polynomial;
+
+        let p = Polynomial::from_roots(&[-3.0f64, 0.5, 2.0, 7.0]);
+        let roots = p.real_roots(1e-10);
+        for (r, e) in roots.iter().zip([-3.0, 0.5, 2.0, 7.0]) {
+            assert!((r - e).abs() < 1e-12, "{} {}", r, e);
+        }
+
+        // x^4 + 1 has the four primitive eighth roots of unity
+        let roots = Polynomial::new(vec![1.0f64, 0.0, 0.0, 0.0, 1.0]).roots();
+        assert_eq!(roots.len(), 4);
+        let s = 0.5f64.sqrt();
+        let expected = [Complex::new(-s, -s), Complex::new(-s, s), Complex::new(s, -s), Complex::new(s, s)];
+        // equal real parts may come in either order, match each root to the nearest
+        for e in expected {
+            assert!(roots.iter().any(|&r| (r - e).abs() < 1e-14));
+        }
+        assert!(Polynomial::new(vec![1.0f64, 0.0, 0.0, 0.0, 1.0]).real_roots(1e-10).is_empty());
+
+        // zero roots are split off exactly, 3 is a double root
+        let p = Polynomial::new(vec![0.0f64, 0.0, 9.0, -6.0, 1.0]);
+        let roots = p.real_roots(1e-6);
+        assert_eq!(roots.len(), 4);
+        assert_eq!(&roots[..2], &[0.0, 0.0]);
+        assert!((roots[2] - 3.0).abs() < 1e-6 && (roots[3] - 3.0).abs() < 1e-6);
+
+        // Wilkinson's polynomial of degree 10 is still well conditioned enough
+        let wilkinson = Polynomial::from_roots(&(1..=10).map(f64::from).collect::<Vec<_>>());
+        for (k, r) in wilkinson.real_roots(1e-8).into_iter().enumerate() {
+            assert!((r - (k + 1) as f64).abs() < 1e-8);
+        }
+        assert!(Polynomial::new(vec![5.0f64]).roots().is_empty());
+
+        // non-finite coefficients give NaN roots instead of a panic in the sort
+        let roots = Polynomial::new(vec![f64::NAN, 1.0, 0.0, 1.0]).roots();
+        assert_eq!(roots.len(), 3);
+        assert!(roots.iter().any(|r| r.re.is_nan()));
+        assert_eq!(Polynomial::new(vec![1.0, f64::INFINITY, 1.0]).roots().len(), 2);
+    }
+
+    #[test]
+    fn test_polyfit() {
+        use gk_math::base::polynomial::Polynomial;
+
+        let truth = Polynomial::new(vec![1.0f64, -2.0, 0.5]);
+        let x: Vec<f64> = (0..20).map(|i| i as f64 * 0.25 - 2.0).collect();
+        let y: Vec<f64> = x.iter().map(|&x| truth.eval(x)).collect();
+        let fit = Polynomial::polyfit(&x, &y, 2).unwrap();
+        for (c, e) in fit.coeffs().iter().zip(truth.coeffs()) {
+            assert!((c - e).abs() < 1e-12);
+        }
+
+        // straight line through noisy points, the residuals sum to zero
+        let y = [1.1f64, 2.9, 5.2, 6.8, 9.1];
+        let x = [0.0f64, 1.0, 2.0, 3.0, 4.0];
+        let line = Polynomial::polyfit(&x, &y, 1).unwrap();
+        assert!((line.coeffs()[1] - 1.99).abs() < 1e-12);
+        assert!((line.coeffs()[0] - 1.04).abs() < 1e-12);
+        let residual: f64 = x.iter().zip(y).map(|(&x, y)| y - line.eval(x)).sum();
+        assert!(residual.abs() < 1e-12);
+
+        assert_eq!(Polynomial::polyfit(&x, &y[..4], 1), Err("Lengths do not match"));
+        assert_eq!(Polynomial::polyfit(&x[..2], &y[..2], 2), Err("Not enough points for the degree"));
+        assert_eq!(Polynomial::polyfit(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0], 1), Err("Vandermonde matrix is rank deficient"));
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_rational_arithmetic() {
+        use gk_math::base::num::Integer;
+        use gk_math::base::rational::Rational;
+
+        let r = |n: i64, d: i64| Rational::new(n, d);
+
+        assert_eq!(r(6, -4), r(-3, 2));
+        assert_eq!((*r(6, -4).numer(), *r(6, -4).denom()), (-3, 2));
+        assert_eq!(r(0, -5), Rational::from(0));
+        assert_eq!(r(1, 6) + r(1, 3), r(1, 2));
+        assert_eq!(r(1, 6) - r(1, 3), r(-1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+        assert_eq!(-r(2, 3), r(-2, 3));
+        assert_eq!(r(-2, 3).abs(), r(2, 3));
+        assert_eq!(r(-2, 3).recip(), r(-3, 2));
+        assert!(r(1, 3) < r(1, 2) && r(-1, 2) < r(-1, 3));
+        assert!(r(4, 2).is_integer());
+
+        let mut x = r(1, 2);
+        x += r(1, 4);
+        x *= r(4, 3);
+        x -= r(1, 1);
+        x /= r(1, 2);
+        assert_eq!(x, Rational::from(0));
+
+        assert_eq!(format!("{}", r(-3, 6)), "-1/2");
+        assert_eq!(format!("{}", r(4, 2)), "2");
+
+        // the lcm keeps the sum representable where a naive cross product overflows
+        let big = i64::MAX / 2;
+        assert_eq!(r(1, big) + r(1, big), r(2, big));
+        assert_eq!(r(1, i64::MAX).checked_add(&r(1, i64::MAX - 1)), None);
+        assert_eq!(r(i64::MAX, 1).checked_mul(&r(2, 1)), None);
+        assert_eq!(Rational::<i64>::checked_new(1, i64::MIN), None);
+        assert_eq!(Rational::<i64>::checked_new(i64::MIN, i64::MIN), None);
+        assert_eq!(Rational::<i64>::checked_new(i64::MIN, -1), None);
+        assert_eq!(Rational::<i64>::checked_new(i64::MIN, 2), Some(r(i64::MIN / 2, 1)));
+        assert_eq!(i64::MIN.checked_gcd(&-1), Some(1));
+        assert_eq!(i64::MIN.checked_gcd(&0), None);
+
+        // comparisons whose cross products overflow
+        let (max, min) = (i64::MAX, i64::MIN);
+        assert!(r(max - 2, max - 1) < r(max - 1, max));
+        assert!(r(max, max - 1) < r(max - 1, max - 2));
+        assert!(r(min + 2, max - 2) < r(min + 1, max - 1));
+        assert!(r(min, 3) < r(max, 3));
+        assert_eq!(r(max, 2).cmp(&r(max, 2)), std::cmp::Ordering::Equal);
+        assert!(r(min, max) < r(-1, 1));
+        assert!(r(min + 1, max) == r(-1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational overflow")]
+    fn test_rational_overflow_panics() {
+        use gk_math::base::rational::Rational;
+
+        let _ = Rational::from(i64::MAX) + Rational::from(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn test_rational_zero_denominator() {
+        use gk_math::base::rational::Rational;
+
+        let _ = Rational::new(1, 0);
+    }
+
+    #[test]
+    fn test_bareiss_determinant() {
+        use gk_math::base::matrix::Mat;
+
+        let a = Mat::new([[2i64, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+        assert_eq!(a.det_bareiss(), 4);
+        // a zero leading pivot needs a row swap
+        let b = Mat::new([[0i64, 1, 2], [3, 4, 5], [6, 7, 9]]);
+        assert_eq!(b.det_bareiss(), -3);
+        assert_eq!(Mat::new([[1i64, 2], [2, 4]]).det_bareiss(), 0);
+        assert_eq!(Mat::<i64, 0, 0>::new([]).det_bareiss(), 1);
+
+        // the n x n Hilbert matrix scaled by lcm(1..2n-1) has an exact integer determinant
+        let h = Mat::<i64, 5, 5>::from_fn(|i, j| 2520 / (i + j + 1) as i64);
+        let det = h.det_bareiss();
+        // det(H_5) = 1 / 266716800000, scaled by 2520^5
+        assert_eq!(det as i128 * 266716800000, 2520i128.pow(5));
+    }
+
+    #[test]
+    fn test_exact_elimination() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::rational::Rational;
+
+        let q = |m: Mat<i64, 3, 4>| m.map(Rational::from);
+
+        let a = q(Mat::new([[1, 2, 1, 1], [2, 4, 0, 6], [1, 2, 2, -1]]));
+        let (rref, pivots) = a.rref();
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(rref, q(Mat::new([[1, 2, 0, 3], [0, 0, 1, -2], [0, 0, 0, 0]])));
+        assert_eq!(a.rank(), 2);
+
+        let nullspace = a.nullspace();
+        assert_eq!(nullspace.len(), 2);
+        for x in &nullspace {
+            assert!((a * *x).iter().all(|&y| y == Rational::from(0)));
+        }
+
+        // the 4 x 4 Hilbert matrix has an integer inverse
+        let h = Mat::<Rational<i64>, 4, 4>::from_fn(|i, j| Rational::new(1, (i + j + 1) as i64));
+        let inverse = h.inverse_exact().unwrap();
+        assert_eq!(inverse[0], [16, -120, 240, -140].map(Rational::from));
+        assert_eq!(h * inverse, Mat::identity());
+        assert_eq!(h.rank(), 4);
+        assert!(h.nullspace().is_empty());
+
+        let singular = Mat::new([[1, 2], [2, 4]]).map(Rational::<i64>::from);
+        assert!(singular.inverse_exact().is_none());
+        assert_eq!(singular.rank(), 1);
+    }
+}
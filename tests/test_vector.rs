@@ -23,4 +23,115 @@ mod tests {
         assert_eq!(v1 * v2, Vec3::new(2.0, 8.0, 18.0));
         assert_eq!(v1.sum(), 6.0);
     }
+
+    #[test]
+    fn test_vec_conversions() {
+        use gk_math::base::f32::{Vec2, Vec3, Vec4};
+
+        let v = Vec3::from((1.0, 2.0, 3.0));
+        let a: [f32; 3] = v.into();
+        let t: (f32, f32, f32) = v.into();
+
+        assert_eq!(a, [1.0, 2.0, 3.0]);
+        assert_eq!(t, (1.0, 2.0, 3.0));
+        assert_eq!(v.extend(4.0), Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v.extend(4.0).truncate(), v);
+        assert_eq!(v.truncate(), Vec2::new(1.0, 2.0));
+        assert_eq!(Vec2::new(1.0, 2.0).extend(3.0), v);
+        assert_eq!(Vec4::new(2.0, 4.0, 6.0, 2.0).project(), v);
+        assert_eq!(Vec3::new(2.0, 4.0, 2.0).project(), Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_f64_i32_family() {
+        use gk_math::base::{f64, i32};
+
+        let v = f64::Vec3::new(3.0, 0.0, 4.0);
+        assert_eq!(v.length(), 5.0);
+        assert_eq!(f64::Mat3::identity() * v, v);
+
+        let m = i32::Mat2::new([[1, 1], [1, 0]]);
+        let v = i32::Vec2::new(1, 0);
+        assert_eq!(m * (m * v), i32::Vec2::new(2, 1));
+        assert_eq!(-v, i32::Vec2::new(-1, 0));
+    }
+
+    #[test]
+    fn test_generic_vector() {
+        use gk_math::base::vector::Vector;
+
+        let a = Vector::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = Vector::new([1.0; 6]);
+
+        assert_eq!(a + b - b, a);
+        assert_eq!(a.dot(&b), 21.0);
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(-a, a * -1.0);
+        assert_eq!(Vector::<f64, 2>::new([3.0, 4.0]).norm(), 5.0);
+        assert_eq!(Vector::<f64, 2>::new([0.0, 2.0]).normalize(), Vector::new([0.0, 1.0]));
+
+        let mut c = a;
+        c += b;
+        c *= 2.0;
+        assert_eq!(c, Vector::from_fn(|i| 2.0 * (i as f64 + 2.0)));
+    }
+
+    #[test]
+    fn test_generic_vector_mat() {
+        use gk_math::base::matrix::Mat;
+        use gk_math::base::vector::Vector;
+        use gk_math::base::f32::Vec3;
+
+        let m = Mat::new([[1, 2, 3], [4, 5, 6]]);
+        let v = Vector::new([1, 0, 1]);
+        assert_eq!(m * v, Vector::new([4, 10]));
+
+        let outer = Vector::new([1, 2]).outer(&Vector::new([3, 4, 5]));
+        assert_eq!(outer, Mat::new([[3, 4, 5], [6, 8, 10]]));
+
+        let col: Mat<i32, 3, 1> = v.into();
+        assert_eq!(col, Mat::new([[1], [0], [1]]));
+        assert_eq!(Vector::from(col), v);
+        assert_eq!(v.to_row(), Mat::new([[1, 0, 1]]));
+        assert_eq!(Vector::from_row(&v.to_row()), v);
+
+        let named = Vec3::new(1.0, 2.0, 3.0);
+        let generic: Vector<f32, 3> = named.into();
+        assert_eq!(generic, Vector::new([1.0, 2.0, 3.0]));
+        assert_eq!(Vec3::from(generic), named);
+    }
+
+    #[test]
+    fn test_orthonormal_frames() {
+        use gk_math::base::f64::Vec3;
+
+        for n in [
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-0.3, 0.1, -1e-9),
+        ] {
+            let (a, b) = n.any_orthonormal_pair();
+            let n = n.normalized();
+            assert!((a.length() - 1.0).abs() < 1e-12 && (b.length() - 1.0).abs() < 1e-12);
+            assert!(a.dot(&b).abs() < 1e-12 && a.dot(&n).abs() < 1e-12 && b.dot(&n).abs() < 1e-12);
+            // right-handed
+            assert!((Vec3::cross(&a, &b) - n).length() < 1e-12);
+        }
+
+        let mut frame = [Vec3::new(1.0, 1.0, 0.0), Vec3::new(2.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 3.0)];
+        assert_eq!(Vec3::orthonormalize(&mut frame), 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((frame[i].dot(&frame[j]) - expected).abs() < 1e-12);
+            }
+        }
+        assert!((frame[0] - Vec3::new(1.0, 1.0, 0.0).normalized()).length() < 1e-15);
+
+        let mut dependent = [Vec3::new(1.0, 0.0, 0.0), Vec3::new(3.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 2.0)];
+        assert_eq!(Vec3::orthonormalize(&mut dependent), 2);
+        assert!(dependent[1].is_zero());
+        assert_eq!(dependent[2], Vec3::new(0.0, 0.0, 1.0));
+    }
 }
\ No newline at end of file